@@ -0,0 +1,96 @@
+use std::{fs, io, path::Path};
+
+use crate::{ParseError, Part, input};
+
+/// Expected answers of a day, read from `dayNN/answers.txt` next to the input.
+///
+/// The file holds one `part1: <answer>` / `part2: <answer>` line per known answer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Self::default();
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                ParseError::new(line, line, "expected answer like part1: 42").at_line(idx)
+            })?;
+            let value = Some(value.trim().to_string());
+
+            match key.trim() {
+                "part1" => answers.part1 = value,
+                "part2" => answers.part2 = value,
+                _ => {
+                    return Err(ParseError::new(line, key, "expected part1 or part2").at_line(idx));
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Loads the known answers of a day next to its input, honouring [`input::INPUT_DIR_VAR`].
+pub fn load(day: u8) -> Result<KnownAnswers, String> {
+    let input_dir = input::input_dir();
+    let path = input::day_file(day, "answers.txt", input_dir.as_deref());
+
+    read(&path).map_err(|err| format!("day {day:02}: {err}"))
+}
+
+/// Reads an answers file - a missing file means no answers are known yet.
+pub fn read(path: &Path) -> Result<KnownAnswers, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(KnownAnswers::default()),
+        Err(err) => return Err(format!("could not read '{}': {err}", path.display())),
+    };
+
+    KnownAnswers::parse(&text).map_err(|err| format!("'{}' is malformed\n{err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = KnownAnswers::parse("part1: 1118\npart2: 6289\n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("1118"));
+        assert_eq!(answers.get(Part::Two), Some("6289"));
+
+        let answers = KnownAnswers::parse("part1: 477").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("477"));
+        assert_eq!(answers.get(Part::Two), None);
+
+        let err = KnownAnswers::parse("part1: 1\npart3: 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "part3"));
+    }
+
+    #[test]
+    fn test_read_answers() {
+        let answers = read(Path::new("assets/day01/answers.txt")).unwrap();
+        assert_eq!(answers.get(Part::One), Some("1118"));
+
+        let answers = read(Path::new("assets/day99/answers.txt")).unwrap();
+        assert_eq!(answers, KnownAnswers::default());
+    }
+}
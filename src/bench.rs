@@ -0,0 +1,175 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    /// Summarizes a non-empty list of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of one day - parts without a solution are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Timing,
+    pub part1: Option<Timing>,
+    pub part2: Option<Timing>,
+}
+
+/// Runs `f` `warmup` times without measuring, then `iterations` (at least once) times measured.
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Timing {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..options.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Timing::from_samples(samples)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+
+    if micros < 1_000.0 {
+        format!("{micros:.1}µs")
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+pub fn render_table(benches: &[DayBench]) -> String {
+    let mut table = format!(
+        "{:>3} | {:<5} | {:>10} | {:>10} | {:>10}\n",
+        "day", "phase", "min", "median", "max"
+    );
+    table.push_str("----+-------+------------+------------+-----------\n");
+
+    for bench in benches {
+        let phases = [
+            ("parse", Some(bench.parse)),
+            ("part1", bench.part1),
+            ("part2", bench.part2),
+        ];
+
+        for (phase, timing) in phases {
+            let (min, median, max) = match timing {
+                Some(timing) => (
+                    format_duration(timing.min),
+                    format_duration(timing.median),
+                    format_duration(timing.max),
+                ),
+                None => ("-".to_string(), "-".to_string(), "-".to_string()),
+            };
+
+            writeln!(
+                table,
+                "{:>3} | {phase:<5} | {min:>10} | {median:>10} | {max:>10}",
+                format!("{:02}", bench.day)
+            )
+            .expect("writing to a string");
+        }
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_timing_from_samples() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+
+        let timing = Timing::from_samples(samples);
+
+        assert_eq!(timing.min, Duration::from_millis(1));
+        assert_eq!(timing.median, Duration::from_millis(3));
+        assert_eq!(timing.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 3,
+        };
+
+        measure(&options, || calls += 1);
+
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_234)), "1.234s");
+    }
+
+    #[test]
+    fn test_render_table() {
+        let timing = Timing::from_samples(vec![Duration::from_micros(10)]);
+        let bench = DayBench {
+            day: 7,
+            parse: timing,
+            part1: Some(timing),
+            part2: None,
+        };
+
+        let table = render_table(&[bench]);
+        let rows: Vec<_> = table.lines().collect();
+
+        assert_eq!(rows.len(), 5);
+        assert_eq!(
+            rows[2],
+            " 07 | parse |     10.0µs |     10.0µs |     10.0µs"
+        );
+        assert_eq!(
+            rows[4],
+            " 07 | part2 |          - |          - |          -"
+        );
+    }
+}
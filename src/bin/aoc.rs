@@ -0,0 +1,462 @@
+use std::{env, path::Path, process::ExitCode, sync::Arc, time::Duration};
+
+use aoc2025::{
+    DAYS, Part, Puzzle, answers,
+    bench::{self, BenchOptions},
+    get_day, input,
+    json::Json,
+    runner::{self, PartResult, Verdict, Verification},
+    scaffold,
+};
+
+const USAGE: &str = "usage:
+  aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json>] [--timeout <secs>] [--trace]
+  aoc verify [day|all] [--timeout <secs>]
+  aoc bench [day|all] [--warmup <n>] [--iterations <n>]
+  aoc diagnose <day|all> [--input <path|->]
+  aoc new <day>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Run(args) => run(args),
+        Command::Verify(puzzles, timeout) => verify(puzzles, timeout),
+        Command::Bench(puzzles, options) => run_bench(puzzles, options),
+        Command::New(day) => new_day(day),
+        Command::Diagnose(puzzles, input) => diagnose(puzzles, input),
+    }
+}
+
+enum Command {
+    Run(RunArgs),
+    Verify(Vec<&'static dyn Puzzle>, Option<Duration>),
+    Bench(Vec<&'static dyn Puzzle>, BenchOptions),
+    New(u8),
+    Diagnose(Vec<&'static dyn Puzzle>, Option<String>),
+}
+
+struct RunArgs {
+    puzzles: Vec<&'static dyn Puzzle>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    format: Format,
+    timeout: Option<Duration>,
+    trace: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format '{value}' - expected text or json")),
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    match args.next().map(String::as_str) {
+        Some("run") => parse_run_args(args),
+        Some("verify") => parse_verify_args(args),
+        Some("bench") => parse_bench_args(args),
+        Some("diagnose") => parse_diagnose_args(args),
+        Some("new") => {
+            let arg = args.next().ok_or("missing day")?;
+            let day = arg.parse().map_err(|_| format!("invalid day '{arg}'"))?;
+
+            if let Some(arg) = args.next() {
+                return Err(format!("unknown argument '{arg}'"));
+            }
+
+            Ok(Command::New(day))
+        }
+        Some(other) => Err(format!("unknown command '{other}'")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_days(arg: &str) -> Result<Vec<&'static dyn Puzzle>, String> {
+    if arg == "all" {
+        return Ok(DAYS.to_vec());
+    }
+
+    let day: u8 = arg.parse().map_err(|_| format!("invalid day '{arg}'"))?;
+    let puzzle = get_day(day).ok_or(format!("day {day} is not solved yet"))?;
+
+    Ok(vec![puzzle])
+}
+
+fn parse_run_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let puzzles = parse_days(args.next().ok_or("missing day")?)?;
+
+    let mut parts = Part::ALL.to_vec();
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut timeout = None;
+    let mut trace = false;
+
+    while let Some(arg) = args.next() {
+        if arg == "--trace" {
+            trace = true;
+            continue;
+        }
+
+        let value = args.next().ok_or(format!("missing value for '{arg}'"))?;
+
+        match arg.as_str() {
+            "--part" => parts = vec![Part::try_from(value.as_str())?],
+            "--input" => input = Some(value.clone()),
+            "--format" => format = Format::try_from(value.as_str())?,
+            "--timeout" => timeout = Some(parse_timeout(value)?),
+            _ => return Err(format!("unknown argument '{arg}'")),
+        }
+    }
+
+    if input.is_some() && puzzles.len() > 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if trace && format == Format::Json {
+        return Err("--trace can only be used with --format text".to_string());
+    }
+
+    Ok(Command::Run(RunArgs {
+        puzzles,
+        parts,
+        input,
+        format,
+        timeout,
+        trace,
+    }))
+}
+
+fn parse_verify_args<'a>(args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut args = args.peekable();
+    let puzzles = match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(day) => parse_days(day)?,
+        None => DAYS.to_vec(),
+    };
+
+    let mut timeout = None;
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("missing value for '{arg}'"))?;
+
+        match arg.as_str() {
+            "--timeout" => timeout = Some(parse_timeout(value)?),
+            _ => return Err(format!("unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(Command::Verify(puzzles, timeout))
+}
+
+fn parse_diagnose_args<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let puzzles = parse_days(args.next().ok_or("missing day")?)?;
+
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("missing value for '{arg}'"))?;
+
+        match arg.as_str() {
+            "--input" => input = Some(value.clone()),
+            _ => return Err(format!("unknown argument '{arg}'")),
+        }
+    }
+
+    if input.is_some() && puzzles.len() > 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    Ok(Command::Diagnose(puzzles, input))
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or(format!(
+            "invalid timeout '{value}' - expected seconds like 10 or 0.5"
+        ))
+}
+
+fn parse_bench_args<'a>(args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut args = args.peekable();
+    let puzzles = match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(day) => parse_days(day)?,
+        None => DAYS.to_vec(),
+    };
+
+    let mut options = BenchOptions::default();
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("missing value for '{arg}'"))?;
+        let value: usize = value
+            .parse()
+            .map_err(|_| format!("invalid number '{value}' for '{arg}'"))?;
+
+        match arg.as_str() {
+            "--warmup" => options.warmup = value,
+            "--iterations" => options.iterations = value,
+            _ => return Err(format!("unknown argument '{arg}'")),
+        }
+    }
+
+    Ok(Command::Bench(puzzles, options))
+}
+
+fn load_input(puzzle: &dyn Puzzle, input: Option<&str>) -> Result<String, String> {
+    input::load(puzzle.day(), input).map_err(|err| format!("day {:02}: {err}", puzzle.day()))
+}
+
+/// Solves the given parts of a day on its loaded input, giving up on a part after `timeout`.
+///
+/// Also returns the hash of the input, if it could be loaded.
+fn solve(
+    puzzle: &'static dyn Puzzle,
+    input: Result<String, String>,
+    parts: &[Part],
+    timeout: Option<Duration>,
+) -> (Option<String>, Result<Vec<PartResult>, String>) {
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => return (None, Err(err)),
+    };
+
+    let input_hash = input::hash(&input);
+    let results = runner::run_with_timeout(puzzle, input.into(), parts, timeout)
+        .map_err(|err| err.to_string());
+
+    (Some(input_hash), results)
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let mut all_solved = true;
+    let mut records = Vec::new();
+
+    for puzzle in args.puzzles {
+        let input = load_input(puzzle, args.input.as_deref());
+
+        if args.trace
+            && let Ok(input) = &input
+        {
+            all_solved &= print_trace(puzzle, input, &args.parts, args.timeout);
+        }
+
+        let (input_hash, results) = solve(puzzle, input, &args.parts, args.timeout);
+
+        let results = match results {
+            Ok(results) => results,
+            Err(err) => {
+                all_solved = false;
+
+                match args.format {
+                    Format::Text => eprintln!("error: {err}"),
+                    Format::Json => records.extend(args.parts.iter().map(|part| {
+                        runner::error_json(puzzle.day(), *part, &err, input_hash.as_deref())
+                    })),
+                }
+                continue;
+            }
+        };
+
+        for result in results {
+            all_solved &= result.is_solved();
+
+            match args.format {
+                Format::Text => println!("{result}"),
+                Format::Json => {
+                    records.push(result.to_json(input_hash.as_deref().unwrap_or_default()))
+                }
+            }
+        }
+    }
+
+    if args.format == Format::Json {
+        println!("{}", Json::Array(records));
+    }
+
+    if all_solved {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Prints the step by step walkthrough of every part, `false` if the input can't be parsed or a walkthrough takes
+/// longer than `timeout`.
+fn print_trace(
+    puzzle: &'static dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    timeout: Option<Duration>,
+) -> bool {
+    let input: Arc<str> = input.into();
+    let mut traced = true;
+
+    for part in parts {
+        match runner::trace_with_timeout(puzzle, Arc::clone(&input), *part, timeout) {
+            Ok(Some(lines)) => {
+                for line in lines {
+                    println!("Day {:02} Part {part} {line}", puzzle.day());
+                }
+            }
+            Ok(None) => {
+                println!(
+                    "Day {:02} Part {part} trace: TIMEOUT (after {})",
+                    puzzle.day(),
+                    bench::format_duration(timeout.unwrap_or_default())
+                );
+                traced = false;
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                return false;
+            }
+        }
+    }
+
+    traced
+}
+
+fn verify(puzzles: Vec<&'static dyn Puzzle>, timeout: Option<Duration>) -> ExitCode {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for puzzle in puzzles {
+        let known_answers = match answers::load(puzzle.day()) {
+            Ok(known_answers) => known_answers,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        let results = match solve(puzzle, load_input(puzzle, None), &Part::ALL, timeout) {
+            (_, Ok(results)) => results,
+            (_, Err(err)) => {
+                eprintln!("error: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        for result in results {
+            let expected = known_answers.get(result.part);
+            let verification = Verification::new(result, expected);
+
+            match verification.verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } => failed += 1,
+                Verdict::Missing => missing += 1,
+            }
+
+            println!("{verification}");
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_bench(puzzles: Vec<&'static dyn Puzzle>, options: BenchOptions) -> ExitCode {
+    let mut benches = Vec::new();
+    let mut all_measured = true;
+
+    for puzzle in puzzles {
+        let input = match input::load(puzzle.day(), None) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: day {:02}: {err}", puzzle.day());
+                all_measured = false;
+                continue;
+            }
+        };
+
+        match puzzle.bench(&input, &options) {
+            Ok(bench) => benches.push(bench),
+            Err(err) => {
+                eprintln!("error: {err}");
+                all_measured = false;
+            }
+        }
+    }
+
+    print!("{}", bench::render_table(&benches));
+
+    if all_measured {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn diagnose(puzzles: Vec<&'static dyn Puzzle>, input: Option<String>) -> ExitCode {
+    let mut all_solvable = true;
+
+    for puzzle in puzzles {
+        let diagnostics = input::load(puzzle.day(), input.as_deref())
+            .map_err(|err| format!("day {:02}: {err}", puzzle.day()))
+            .and_then(|input| puzzle.diagnose(&input).map_err(|err| err.to_string()));
+
+        match diagnostics {
+            Ok(diagnostics) => {
+                for diagnostic in &diagnostics {
+                    println!("Day {:02} {diagnostic}", puzzle.day());
+                }
+                all_solvable &= diagnostics.is_empty();
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                all_solvable = false;
+            }
+        }
+    }
+
+    if all_solvable {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    match scaffold::create(Path::new("."), day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,1109 @@
+use std::fmt::{self, Display};
+
+use crate::{Answer, ParseError, Part, Solution};
+
+/// Which start positions lead to a given number of zeros, and how many instructions have to change to get there.
+pub mod inverse;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Instruction::try_from(line).map_err(|err| err.at_line(idx)))
+            .collect()
+    }
+
+    fn part1(instructions: &Self::Input) -> Answer {
+        let mut dial = SafeDial::<100>::new(50);
+
+        for instruction in instructions {
+            dial.move_dial(instruction);
+        }
+
+        dial.count.into()
+    }
+
+    fn part2(instructions: &Self::Input) -> Answer {
+        let mut dial = SafeDial::<100>::new(50);
+
+        for instruction in instructions {
+            dial.move_dial_with_count(instruction);
+        }
+
+        dial.count.into()
+    }
+
+    fn trace(instructions: &Self::Input, part: Part) -> Vec<String> {
+        let mut dial = SafeDial::<100>::with_history(50);
+
+        for instruction in instructions {
+            match part {
+                Part::One => dial.move_dial(instruction),
+                Part::Two => dial.move_dial_with_count(instruction),
+            }
+        }
+
+        dial.history()
+            .map(|(step, instruction, position, zeros)| {
+                format!("step {step}: {instruction} -> {position} (zeros {zeros})")
+            })
+            .collect()
+    }
+}
+
+/// Dial with `M` positions counting how often it points at 0 - a [`Dial`] with a single target.
+///
+/// Created via [`SafeDial::with_history`] it also records every turn, so it can be rewound and inspected.
+pub struct SafeDial<const M: isize> {
+    inner: Dial,
+    count: u128,
+    history: Option<History>,
+}
+
+/// Dial and count before the first recorded turn, and the turns with the dial and count after each of them.
+struct History {
+    start: (Dial, u128),
+    turns: Vec<(Instruction, Dial, u128)>,
+}
+
+impl<const M: isize> SafeDial<M> {
+    pub fn new(init: isize) -> Self {
+        let start = init.rem_euclid(M) as u128;
+        let inner = Dial::new(M as u128, start, [0]).expect("start is on the dial");
+
+        Self {
+            inner,
+            count: 0,
+            history: None,
+        }
+    }
+
+    pub fn with_history(init: isize) -> Self {
+        let mut dial = Self::new(init);
+        dial.history = Some(History {
+            start: (dial.inner.clone(), 0),
+            turns: Vec::new(),
+        });
+        dial
+    }
+
+    pub fn position(&self) -> isize {
+        self.inner.position as isize
+    }
+
+    pub fn count(&self) -> u128 {
+        self.count
+    }
+
+    /// Turns the dial and counts whether it lands on 0.
+    pub fn move_dial(&mut self, instruction: &Instruction) {
+        self.count += self.inner.turn(instruction)[0].landed;
+        self.record(instruction);
+    }
+
+    /// Turns the dial and counts every click that points it at 0, in constant time.
+    pub fn move_dial_with_count(&mut self, instruction: &Instruction) {
+        self.count += self.inner.turn(instruction)[0].passed;
+        self.record(instruction);
+    }
+
+    fn record(&mut self, instruction: &Instruction) {
+        if let Some(history) = &mut self.history {
+            history
+                .turns
+                .push((*instruction, self.inner.clone(), self.count));
+        }
+    }
+
+    /// Recorded turns as `(step, instruction, position, zeros so far)`, steps starting at 1.
+    pub fn history(&self) -> impl Iterator<Item = (usize, &Instruction, isize, u128)> + '_ {
+        self.history
+            .iter()
+            .flat_map(|history| &history.turns)
+            .enumerate()
+            .map(|(idx, (instruction, dial, count))| {
+                (idx + 1, instruction, dial.position as isize, *count)
+            })
+    }
+
+    /// Restores the dial as it was after `step` recorded turns, forgetting the turns after it.
+    pub fn rewind(&mut self, step: usize) -> Result<(), String> {
+        let history = self.history.as_mut().ok_or("dial has no history")?;
+
+        let (dial, count) = match step {
+            0 => history.start.clone(),
+            _ => {
+                let (_, dial, count) = history.turns.get(step - 1).ok_or(format!(
+                    "step {step} is not recorded, the dial has only turned {} times",
+                    history.turns.len()
+                ))?;
+                (dial.clone(), *count)
+            }
+        };
+
+        history.turns.truncate(step);
+        self.inner = dial;
+        self.count = count;
+
+        Ok(())
+    }
+
+    /// Takes back the last recorded turn, returning it.
+    pub fn undo(&mut self) -> Option<Instruction> {
+        let history = self.history.as_ref()?;
+        let (instruction, ..) = *history.turns.last()?;
+
+        self.rewind(history.turns.len() - 1)
+            .expect("the previous step is recorded");
+
+        Some(instruction)
+    }
+
+    /// How often every position was landed on and pointed at by the recorded turns, by position.
+    pub fn histogram(&self) -> Vec<Hits> {
+        let size = M as u128;
+        let mut histogram = vec![Hits::default(); M as usize];
+
+        let Some(history) = &self.history else {
+            return histogram;
+        };
+
+        let mut position = history.start.0.position;
+        for (instruction, dial, _) in &history.turns {
+            // every full revolution points at each position once, the rest only at the positions right after the start
+            let clicks = instruction.clicks();
+            for hits in &mut histogram {
+                hits.passed += clicks / size;
+            }
+            for click in 1..=clicks % size {
+                let passed = match instruction {
+                    Instruction::Left(_) => (position + size - click) % size,
+                    Instruction::Right(_) => (position + click) % size,
+                };
+                histogram[passed as usize].passed += 1;
+            }
+
+            position = dial.position;
+            histogram[position as usize].landed += 1;
+        }
+
+        histogram
+    }
+}
+
+/// How often a dial pointed at a target position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Hits {
+    /// Turns that ended on the target.
+    pub landed: u128,
+    /// Clicks that pointed the dial at the target, including those a turn ended on.
+    pub passed: u128,
+}
+
+/// Dial of any size that counts the hits of a set of target positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u128,
+    position: u128,
+    targets: Vec<(u128, Hits)>,
+}
+
+impl Dial {
+    pub fn new(
+        size: u128,
+        start: u128,
+        targets: impl IntoIterator<Item = u128>,
+    ) -> Result<Self, String> {
+        if size == 0 {
+            return Err("a dial needs at least one position".to_string());
+        }
+
+        let targets: Vec<_> = targets
+            .into_iter()
+            .map(|target| (target, Hits::default()))
+            .collect();
+        if let Some(position) = [start]
+            .iter()
+            .chain(targets.iter().map(|(target, _)| target))
+            .find(|position| **position >= size)
+        {
+            return Err(format!(
+                "position {position} is not on a dial with {size} positions"
+            ));
+        }
+
+        Ok(Self {
+            size,
+            position: start,
+            targets,
+        })
+    }
+
+    pub fn position(&self) -> u128 {
+        self.position
+    }
+
+    /// Target positions with their hits so far, in the order they were given.
+    pub fn targets(&self) -> &[(u128, Hits)] {
+        &self.targets
+    }
+
+    /// Position of the dial after turning it, without moving it.
+    pub fn position_after(&self, instruction: &Instruction) -> u128 {
+        // whole revolutions don't change the position
+        let clicks = instruction.clicks() % self.size;
+
+        match instruction {
+            Instruction::Left(_) => self.clicks_between(clicks, self.position),
+            Instruction::Right(_) => self.forward(self.position, clicks),
+        }
+    }
+
+    /// Clicks to the right from `from` to `to`, both on the dial - also `to - from` wrapped onto the dial.
+    ///
+    /// Never adds the size, so it can't overflow for dials close to `u128::MAX` positions.
+    fn clicks_between(&self, from: u128, to: u128) -> u128 {
+        if from <= to {
+            to - from
+        } else {
+            self.size - (from - to)
+        }
+    }
+
+    /// Position `clicks` to the right of `position`, with fewer clicks than the dial has positions.
+    fn forward(&self, position: u128, clicks: u128) -> u128 {
+        if clicks < self.size - position {
+            position + clicks
+        } else {
+            clicks - (self.size - position)
+        }
+    }
+
+    /// Turns the dial, returning the hits of every target during this turn.
+    ///
+    /// Runs in constant time per target, however many clicks the turn has.
+    pub fn turn(&mut self, instruction: &Instruction) -> Vec<Hits> {
+        let end = self.position_after(instruction);
+        let clicks = instruction.clicks();
+
+        let hits: Vec<Hits> = self
+            .targets
+            .iter()
+            .map(|(target, _)| {
+                // clicks until the dial points at the target for the first time, after that it does once per revolution
+                let first = match instruction {
+                    Instruction::Left(_) => self.clicks_between(*target, self.position),
+                    Instruction::Right(_) => self.clicks_between(self.position, *target),
+                };
+                let first = if first == 0 { self.size } else { first };
+
+                Hits {
+                    landed: (end == *target) as u128,
+                    passed: if clicks >= first {
+                        (clicks - first) / self.size + 1
+                    } else {
+                        0
+                    },
+                }
+            })
+            .collect();
+
+        for ((_, total), hits) in self.targets.iter_mut().zip(&hits) {
+            total.landed += hits.landed;
+            total.passed += hits.passed;
+        }
+        self.position = end;
+
+        hits
+    }
+
+    /// Turns the dial one click at a time - the reference for [`Dial::turn`].
+    #[cfg(test)]
+    fn turn_by_clicks(&mut self, instruction: &Instruction) {
+        for _ in 0..instruction.clicks() {
+            self.position = match instruction {
+                Instruction::Left(_) => self.clicks_between(1, self.position),
+                Instruction::Right(_) => self.forward(self.position, 1),
+            };
+
+            for (target, hits) in &mut self.targets {
+                hits.passed += (self.position == *target) as u128;
+            }
+        }
+
+        for (target, hits) in &mut self.targets {
+            hits.landed += (self.position == *target) as u128;
+        }
+    }
+}
+
+/// Turn of the dial by a number of clicks - left towards lower numbers, right towards higher ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Left(u128),
+    Right(u128),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Left(clicks) => write!(f, "L{clicks}"),
+            Instruction::Right(clicks) => write!(f, "R{clicks}"),
+        }
+    }
+}
+
+impl Instruction {
+    pub fn clicks(&self) -> u128 {
+        match self {
+            Instruction::Left(clicks) | Instruction::Right(clicks) => *clicks,
+        }
+    }
+
+    /// Single turn ending where turning by `self` and then by `other` ends, `None` if it overflows.
+    pub fn combine(&self, other: &Instruction) -> Option<Instruction> {
+        match (self, other) {
+            (Instruction::Left(a), Instruction::Left(b)) => {
+                a.checked_add(*b).map(Instruction::Left)
+            }
+            (Instruction::Right(a), Instruction::Right(b)) => {
+                a.checked_add(*b).map(Instruction::Right)
+            }
+            (Instruction::Left(left), Instruction::Right(right))
+            | (Instruction::Right(right), Instruction::Left(left)) => Some(if left > right {
+                Instruction::Left(left - right)
+            } else {
+                Instruction::Right(right - left)
+            }),
+        }
+    }
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let get_val = |delim: char| {
+            let (_, val) = value
+                .split_once(delim)
+                .ok_or_else(|| ParseError::new(value, value, "invalid input"))?;
+            let val = val
+                .parse()
+                .map_err(|_| ParseError::new(value, val, "invalid number"))?;
+
+            Ok::<u128, ParseError>(val)
+        };
+
+        match value.chars().peekable().peek() {
+            Some(char) if *char == 'L' => {
+                let val = get_val('L')?;
+
+                Ok(Self::Left(val))
+            }
+            Some(char) if *char == 'R' => {
+                let val = get_val('R')?;
+
+                Ok(Self::Right(val))
+            }
+            _ => Err(ParseError::new(
+                value,
+                value.get(..1).unwrap_or(value),
+                "expected L or R",
+            )),
+        }
+    }
+}
+
+/// Combination lock of several named dials, some of them geared to others.
+///
+/// Written as declarations followed by instructions that address a dial by name:
+///
+/// ```text
+/// dial A 100 50
+/// dial B 10 0
+/// gear A B -2
+/// A:L68
+/// B:R3
+/// ```
+///
+/// `dial <name> <size> <start>` adds a dial and `gear <from> <to> <ratio>` turns `to` by `ratio` clicks for every
+/// click of `from` - in the opposite direction if the ratio is negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    names: Vec<String>,
+    dials: Vec<Dial>,
+    gears: Vec<Gear>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Gear {
+    from: usize,
+    to: usize,
+    ratio: i64,
+}
+
+/// Turn of a single dial of a [`Lock`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockInstruction {
+    pub dial: usize,
+    pub instruction: Instruction,
+}
+
+/// Positions and zero counts of every dial of a [`Lock`], in declaration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockState {
+    pub positions: Vec<u128>,
+    pub zeros: Vec<Hits>,
+    names: Vec<String>,
+}
+
+impl Display for LockState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, ((name, position), zeros)) in self
+            .names
+            .iter()
+            .zip(&self.positions)
+            .zip(&self.zeros)
+            .enumerate()
+        {
+            if idx > 0 {
+                write!(f, " ")?;
+            }
+            write!(
+                f,
+                "{name}={position} ({} landed, {} passed)",
+                zeros.landed, zeros.passed
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Lock {
+    /// Parses the declarations and instructions of a lock.
+    pub fn parse(input: &str) -> Result<(Lock, Vec<LockInstruction>), ParseError> {
+        let mut lock = Lock {
+            names: Vec::new(),
+            dials: Vec::new(),
+            gears: Vec::new(),
+        };
+        let mut instructions = Vec::new();
+
+        for (idx, line) in input.lines().enumerate() {
+            lock.parse_line(line, &mut instructions)
+                .map_err(|err| err.at_line(idx))?;
+        }
+
+        Ok((lock, instructions))
+    }
+
+    fn parse_line(
+        &mut self,
+        line: &str,
+        instructions: &mut Vec<LockInstruction>,
+    ) -> Result<(), ParseError> {
+        let words: Vec<_> = line.split_ascii_whitespace().collect();
+
+        match words.as_slice() {
+            [] => {}
+            ["dial", name, size, start] => {
+                if self.find(name).is_some() {
+                    return Err(ParseError::new(line, name, "dial is already declared"));
+                }
+                if !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(ParseError::new(line, name, "expected a name like A"));
+                }
+
+                let size = parse_number(line, size)?;
+                let start = parse_number(line, start)?;
+                let dial = Dial::new(size, start, [0])
+                    .map_err(|err| ParseError::new(line, line.trim(), err))?;
+
+                self.names.push(name.to_string());
+                self.dials.push(dial);
+            }
+            ["gear", from, to, ratio] => {
+                let from = self.dial_index(line, from)?;
+                let to_idx = self.dial_index(line, to)?;
+                let ratio = ratio
+                    .parse()
+                    .map_err(|_| ParseError::new(line, ratio, "invalid ratio"))?;
+
+                if self.drives(to_idx, from) {
+                    return Err(ParseError::new(
+                        line,
+                        to,
+                        "gear would make the dial turn itself",
+                    ));
+                }
+
+                self.gears.push(Gear {
+                    from,
+                    to: to_idx,
+                    ratio,
+                });
+            }
+            [word] => {
+                let (name, turn) = word
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::new(line, word, "expected a turn like A:L68"))?;
+                let dial = self.dial_index(line, name)?;
+
+                // the column of errors within the turn is relative to the turn
+                let offset = line[..turn.as_ptr() as usize - line.as_ptr() as usize]
+                    .chars()
+                    .count();
+                let instruction = Instruction::try_from(turn).map_err(|err| ParseError {
+                    column: err.column + offset,
+                    source_line: line.to_string(),
+                    ..err
+                })?;
+
+                instructions.push(LockInstruction { dial, instruction });
+            }
+            [word, ..] => {
+                return Err(ParseError::new(
+                    line,
+                    word,
+                    "expected 'dial <name> <size> <start>', 'gear <from> <to> <ratio>' or a turn like A:L68",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|other| other == name)
+    }
+
+    fn dial_index(&self, line: &str, name: &str) -> Result<usize, ParseError> {
+        self.find(name)
+            .ok_or_else(|| ParseError::new(line, name, "unknown dial"))
+    }
+
+    /// Whether turning dial `from` turns dial `to`, directly or through other gears.
+    fn drives(&self, from: usize, to: usize) -> bool {
+        let mut stack = vec![from];
+        let mut seen = vec![false; self.dials.len()];
+
+        while let Some(dial) = stack.pop() {
+            if dial == to {
+                return true;
+            }
+            if std::mem::replace(&mut seen[dial], true) {
+                continue;
+            }
+            stack.extend(
+                self.gears
+                    .iter()
+                    .filter(|gear| gear.from == dial)
+                    .map(|gear| gear.to),
+            );
+        }
+
+        false
+    }
+
+    /// Turns a dial and every dial geared to it, returning the state of the whole lock afterwards.
+    ///
+    /// A dial driven through several gears only turns by the net of what they add up to. Fails without turning
+    /// anything if a geared turn would overflow.
+    pub fn turn(&mut self, turn: &LockInstruction) -> Result<LockState, String> {
+        let mut net: Vec<Option<Instruction>> = vec![None; self.dials.len()];
+        net[turn.dial] = Some(turn.instruction);
+
+        // every dial has received all its turns once the dials driving it are done
+        for dial in self.topological_order() {
+            let Some(instruction) = net[dial] else {
+                continue;
+            };
+
+            for gear in self.gears.iter().filter(|gear| gear.from == dial) {
+                let overflow = || format!("turn of dial {} overflows", self.names[gear.to]);
+
+                let clicks = instruction
+                    .clicks()
+                    .checked_mul(gear.ratio.unsigned_abs() as u128)
+                    .ok_or_else(overflow)?;
+                let geared = match (instruction, gear.ratio < 0) {
+                    (Instruction::Left(_), false) | (Instruction::Right(_), true) => {
+                        Instruction::Left(clicks)
+                    }
+                    (Instruction::Right(_), false) | (Instruction::Left(_), true) => {
+                        Instruction::Right(clicks)
+                    }
+                };
+
+                net[gear.to] = Some(match net[gear.to] {
+                    Some(other) => other.combine(&geared).ok_or_else(overflow)?,
+                    None => geared,
+                });
+            }
+        }
+
+        for (dial, instruction) in net.iter().enumerate() {
+            if let Some(instruction) = instruction {
+                self.dials[dial].turn(instruction);
+            }
+        }
+
+        Ok(self.state())
+    }
+
+    /// Dials ordered so every dial comes after all dials geared to it - gears never form a cycle.
+    fn topological_order(&self) -> Vec<usize> {
+        let mut drivers = vec![0; self.dials.len()];
+        for gear in &self.gears {
+            drivers[gear.to] += 1;
+        }
+
+        let mut ready: Vec<usize> = (0..self.dials.len())
+            .filter(|dial| drivers[*dial] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.dials.len());
+
+        while let Some(dial) = ready.pop() {
+            order.push(dial);
+            for gear in self.gears.iter().filter(|gear| gear.from == dial) {
+                drivers[gear.to] -= 1;
+                if drivers[gear.to] == 0 {
+                    ready.push(gear.to);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Runs all instructions, returning the state of the lock after each of them.
+    pub fn simulate(&mut self, instructions: &[LockInstruction]) -> Result<Vec<LockState>, String> {
+        instructions.iter().map(|turn| self.turn(turn)).collect()
+    }
+
+    pub fn state(&self) -> LockState {
+        LockState {
+            positions: self.dials.iter().map(Dial::position).collect(),
+            zeros: self.dials.iter().map(|dial| dial.targets()[0].1).collect(),
+            names: self.names.clone(),
+        }
+    }
+}
+
+fn parse_number(line: &str, input: &str) -> Result<u128, ParseError> {
+    input
+        .parse()
+        .map_err(|_| ParseError::new(line, input, "invalid number"))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::xorshift;
+
+    #[test]
+    fn test_instruction_parsing() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+        let instructions: Vec<Instruction> =
+            input.lines().flat_map(|line| line.try_into()).collect();
+
+        assert_eq!(instructions.len(), 10);
+
+        assert_eq!(instructions[0], Instruction::Left(68));
+        assert_eq!(instructions[1], Instruction::Left(30));
+        assert_eq!(instructions[2], Instruction::Right(48));
+        assert_eq!(instructions[3], Instruction::Left(5));
+        assert_eq!(instructions[4], Instruction::Right(60));
+        assert_eq!(instructions[5], Instruction::Left(55));
+        assert_eq!(instructions[6], Instruction::Left(1));
+        assert_eq!(instructions[7], Instruction::Left(99));
+        assert_eq!(instructions[8], Instruction::Right(14));
+        assert_eq!(instructions[9], Instruction::Left(82));
+    }
+
+    #[test]
+    fn test_invalid_instructions() {
+        let err = Day01::parse("L68\nX30\nR48").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+
+        let err = Day01::parse("L68\nL30\nR4x8").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 2, "4x8"));
+    }
+
+    #[test]
+    fn test_wrapping_dial_ops() {
+        let mut dial = SafeDial::<100>::new(50);
+        dial.move_dial(&Instruction::Left(68));
+        assert_eq!(dial.position(), 82);
+        dial.move_dial(&Instruction::Right(18));
+        assert_eq!(dial.position(), 0);
+    }
+
+    #[test]
+    fn test_counting_zeros() {
+        let mut dial = SafeDial::<100>::new(50);
+        dial.move_dial(&Instruction::Left(68));
+        dial.move_dial(&Instruction::Left(30));
+        dial.move_dial(&Instruction::Right(48));
+        dial.move_dial(&Instruction::Left(5));
+        dial.move_dial(&Instruction::Right(60));
+        dial.move_dial(&Instruction::Left(55));
+        dial.move_dial(&Instruction::Left(1));
+        dial.move_dial(&Instruction::Left(99));
+        dial.move_dial(&Instruction::Right(14));
+        dial.move_dial(&Instruction::Right(82));
+
+        assert_eq!(dial.count, 3);
+    }
+
+    #[test]
+    fn test_counting_zeros_and_passing_zeros() {
+        let mut dial = SafeDial::<100>::new(50);
+        dial.move_dial_with_count(&Instruction::Left(68));
+        assert_eq!(dial.position(), 82);
+        assert_eq!(dial.count, 1);
+        dial.move_dial_with_count(&Instruction::Left(30));
+        assert_eq!(dial.count, 1);
+        assert_eq!(dial.position(), 52);
+        dial.move_dial_with_count(&Instruction::Right(48));
+        assert_eq!(dial.position(), 0);
+        assert_eq!(dial.count, 2);
+        dial.move_dial_with_count(&Instruction::Left(5));
+        assert_eq!(dial.position(), 95);
+        dial.move_dial_with_count(&Instruction::Right(60));
+        assert_eq!(dial.position(), 55);
+        dial.move_dial_with_count(&Instruction::Left(55));
+        assert_eq!(dial.position(), 0);
+        dial.move_dial_with_count(&Instruction::Left(1));
+        assert_eq!(dial.position(), 99);
+        dial.move_dial_with_count(&Instruction::Left(99));
+        assert_eq!(dial.position(), 0);
+        dial.move_dial_with_count(&Instruction::Right(14));
+        assert_eq!(dial.position(), 14);
+        dial.move_dial_with_count(&Instruction::Left(82));
+        assert_eq!(dial.position(), 32);
+
+        assert_eq!(dial.count, 6);
+    }
+
+    #[test]
+    fn test_huge_turns() {
+        let instruction = Instruction::try_from("R1000000000000000000000000000000").unwrap();
+
+        let mut dial = SafeDial::<100>::new(50);
+        dial.move_dial_with_count(&instruction);
+        assert_eq!(dial.position(), 50);
+        assert_eq!(dial.count, 10_000_000_000_000_000_000_000_000_000);
+
+        let mut dial = SafeDial::<100>::new(0);
+        dial.move_dial_with_count(&Instruction::Left(u128::MAX));
+        assert_eq!(dial.count, u128::MAX / 100);
+    }
+
+    #[test]
+    fn test_count_like_clicks() {
+        let mut next = xorshift(0x2545_f491_4f6c_dd1d);
+
+        for _ in 0..200 {
+            let size = next(120) as u128 + 1;
+            let start = next(size as u64) as u128;
+            let targets: Vec<_> = (0..3).map(|_| next(size as u64) as u128).collect();
+
+            let mut dial = Dial::new(size, start, targets.clone()).unwrap();
+            let mut reference = dial.clone();
+
+            for _ in 0..50 {
+                let clicks = next(450) as u128;
+                let instruction = if next(2) == 0 {
+                    Instruction::Left(clicks)
+                } else {
+                    Instruction::Right(clicks)
+                };
+
+                dial.turn(&instruction);
+                reference.turn_by_clicks(&instruction);
+
+                assert_eq!(
+                    dial, reference,
+                    "size {size}, start {start}, targets {targets:?}, {instruction:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dial_targets() {
+        let mut dial = Dial::new(10, 3, [0, 5]).unwrap();
+
+        let hits = dial.turn(&Instruction::Right(22));
+        assert_eq!(dial.position(), 5);
+        assert_eq!(
+            hits,
+            vec![
+                Hits {
+                    landed: 0,
+                    passed: 2
+                },
+                Hits {
+                    landed: 1,
+                    passed: 3
+                }
+            ]
+        );
+
+        dial.turn(&Instruction::Left(5));
+        assert_eq!(
+            dial.targets(),
+            &[
+                (
+                    0,
+                    Hits {
+                        landed: 1,
+                        passed: 3
+                    }
+                ),
+                (
+                    5,
+                    Hits {
+                        landed: 1,
+                        passed: 3
+                    }
+                )
+            ]
+        );
+
+        assert_eq!(
+            Dial::new(10, 3, [10]),
+            Err("position 10 is not on a dial with 10 positions".to_string())
+        );
+        assert!(Dial::new(0, 0, []).is_err());
+    }
+
+    #[test]
+    fn test_huge_dial() {
+        let mut dial = Dial::new(u128::MAX, 5, [0, u128::MAX - 1]).unwrap();
+
+        let hits = dial.turn(&Instruction::Left(6));
+        assert_eq!(dial.position(), u128::MAX - 1);
+        assert_eq!(hits[0].passed, 1);
+        assert_eq!(hits[1].landed, 1);
+
+        dial.turn(&Instruction::Right(2));
+        assert_eq!(dial.position(), 1);
+        assert_eq!(dial.targets()[0].1.passed, 2);
+
+        let mut reference = Dial::new(u128::MAX, u128::MAX - 2, [0, 1]).unwrap();
+        let mut dial = reference.clone();
+        for instruction in [
+            Instruction::Right(4),
+            Instruction::Left(7),
+            Instruction::Right(3),
+        ] {
+            dial.turn(&instruction);
+            reference.turn_by_clicks(&instruction);
+        }
+        assert_eq!(dial, reference);
+    }
+
+    #[test]
+    fn test_safe_dial_normalises_start() {
+        assert_eq!(SafeDial::<100>::new(-1).position(), 99);
+        assert_eq!(SafeDial::<100>::new(250).position(), 50);
+    }
+
+    #[test]
+    fn test_lock() {
+        let input =
+            "dial A 100 50\ndial B 10 0\ndial C 4 1\ngear A B -2\ngear B C 1\n\nA:L68\nB:R3\nC:L1";
+        let (mut lock, instructions) = Lock::parse(input).unwrap();
+
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[0],
+            LockInstruction {
+                dial: 0,
+                instruction: Instruction::Left(68)
+            }
+        );
+
+        let states = lock.simulate(&instructions).unwrap();
+        let states: Vec<_> = states.iter().map(LockState::to_string).collect();
+        assert_eq!(
+            states,
+            vec![
+                "A=82 (0 landed, 1 passed) B=6 (0 landed, 13 passed) C=1 (0 landed, 34 passed)",
+                "A=82 (0 landed, 1 passed) B=9 (0 landed, 13 passed) C=0 (1 landed, 35 passed)",
+                "A=82 (0 landed, 1 passed) B=9 (0 landed, 13 passed) C=3 (1 landed, 35 passed)",
+            ]
+        );
+
+        // turning the lock twice as far as a u128 can count fails before anything moves
+        let (mut lock, _) = Lock::parse("dial A 10 0\ndial B 10 0\ngear A B 2").unwrap();
+        let before = lock.state();
+        let turn = LockInstruction {
+            dial: 0,
+            instruction: Instruction::Right(u128::MAX),
+        };
+        assert_eq!(
+            lock.turn(&turn),
+            Err("turn of dial B overflows".to_string())
+        );
+        assert_eq!(lock.state(), before);
+    }
+
+    #[test]
+    fn test_geared_net_turns() {
+        // D is driven through B and C in opposite directions, so it does not move at all
+        let input = "dial A 10 0\ndial B 10 0\ndial C 10 0\ndial D 10 5\ngear A B 1\ngear A C 1\ngear B D 1\ngear C D -1\nA:R13";
+        let (mut lock, instructions) = Lock::parse(input).unwrap();
+
+        let state = lock.turn(&instructions[0]).unwrap();
+        assert_eq!(state.positions, vec![3, 3, 3, 5]);
+        assert_eq!(state.zeros[3].passed, 0);
+
+        // a long chain of diamonds would double the turns per layer if every gear path turned the dial separately
+        let mut input = String::from("dial D0 10 0\n");
+        for layer in 1..=60 {
+            input += &format!(
+                "dial L{layer} 10 0\ndial R{layer} 10 0\ndial D{layer} 10 0\n\
+                 gear D{prev} L{layer} 1\ngear D{prev} R{layer} 1\ngear L{layer} D{layer} 1\ngear R{layer} D{layer} -1\n",
+                prev = layer - 1
+            );
+        }
+        input += "D0:R1";
+        let (mut lock, instructions) = Lock::parse(&input).unwrap();
+
+        let state = lock.turn(&instructions[0]).unwrap();
+        assert_eq!(state.positions[..4], [1, 1, 1, 0]);
+        assert_eq!(state.positions[4..], vec![0; 177]);
+    }
+
+    #[test]
+    fn test_lock_errors() {
+        let cases = [
+            ("dial A 10 0\nB:L3", 2, 1, "B", "unknown dial"),
+            ("dial A 10 0\nA:L3x", 2, 4, "3x", "invalid number"),
+            ("dial A 10 0\nA:X3", 2, 3, "X", "expected L or R"),
+            ("dial A 10 0\nA:L3x  ", 2, 4, "3x", "invalid number"),
+            (
+                "dial A 10 0\ndial A 5 0",
+                2,
+                6,
+                "A",
+                "dial is already declared",
+            ),
+            ("dial A 10 x", 1, 11, "x", "invalid number"),
+            (
+                "dial A 10 0\ndial B 10 0\ngear A B 1\ngear B A 1",
+                4,
+                8,
+                "A",
+                "gear would make the dial turn itself",
+            ),
+            (
+                "dial A 10 0\nturn A L3",
+                2,
+                1,
+                "turn",
+                "expected 'dial <name> <size> <start>', 'gear <from> <to> <ratio>' or a turn like A:L68",
+            ),
+            (
+                "dial A 10 10",
+                1,
+                1,
+                "dial A 10 10",
+                "position 10 is not on a dial with 10 positions",
+            ),
+        ];
+
+        for (input, line, column, text, message) in cases {
+            let err = Lock::parse(input).unwrap_err();
+            assert_eq!(
+                (
+                    err.line,
+                    err.column,
+                    err.text.as_str(),
+                    err.message.as_str()
+                ),
+                (line, column, text, message),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_history() {
+        let instructions = Day01::parse("L68\nL30\nR48\nL5\nR260").unwrap();
+
+        let mut dial = SafeDial::<100>::with_history(50);
+        for instruction in &instructions {
+            dial.move_dial_with_count(instruction);
+        }
+
+        let history: Vec<_> = dial.history().collect();
+        assert_eq!(history[0], (1, &Instruction::Left(68), 82, 1));
+        assert_eq!(history[4], (5, &Instruction::Right(260), 55, 5));
+
+        let histogram = dial.histogram();
+        assert_eq!(histogram.iter().map(|hits| hits.landed).sum::<u128>(), 5);
+        assert_eq!(
+            histogram.iter().map(|hits| hits.passed).sum::<u128>(),
+            68 + 30 + 48 + 5 + 260
+        );
+        assert_eq!(
+            histogram[0],
+            Hits {
+                landed: 1,
+                passed: 5
+            }
+        );
+        assert_eq!(histogram[55].passed, 5);
+
+        assert_eq!(dial.undo(), Some(Instruction::Right(260)));
+        assert_eq!((dial.position(), dial.count()), (95, 2));
+
+        dial.rewind(1).unwrap();
+        assert_eq!((dial.position(), dial.count()), (82, 1));
+        assert_eq!(dial.history().count(), 1);
+        assert_eq!(
+            dial.rewind(2),
+            Err("step 2 is not recorded, the dial has only turned 1 times".to_string())
+        );
+
+        dial.rewind(0).unwrap();
+        assert_eq!((dial.position(), dial.count()), (50, 0));
+        assert_eq!(dial.undo(), None);
+
+        let mut dial = SafeDial::<100>::new(50);
+        dial.move_dial(&Instruction::Left(50));
+        assert_eq!(dial.history().count(), 0);
+        assert_eq!(dial.undo(), None);
+    }
+
+    #[test]
+    fn test_trace() {
+        let instructions = Day01::parse("L68\nR18\nL200").unwrap();
+
+        assert_eq!(
+            Day01::trace(&instructions, Part::One),
+            vec![
+                "step 1: L68 -> 82 (zeros 0)",
+                "step 2: R18 -> 0 (zeros 1)",
+                "step 3: L200 -> 0 (zeros 2)",
+            ]
+        );
+        assert_eq!(
+            Day01::trace(&instructions, Part::Two)[2],
+            "step 3: L200 -> 0 (zeros 4)"
+        );
+    }
+}
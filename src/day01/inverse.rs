@@ -0,0 +1,399 @@
+use super::Instruction;
+
+/// Which zeros are counted, see [`SafeDial::move_dial`](super::SafeDial::move_dial) and
+/// [`SafeDial::move_dial_with_count`](super::SafeDial::move_dial_with_count).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zeros {
+    Landed,
+    Passed,
+}
+
+/// Zeros counted for every start position of a dial with `M` positions, by start position.
+///
+/// Runs in `O(instructions + M)` rather than simulating the instructions once per start.
+pub fn zeros_by_start<const M: isize>(instructions: &[Instruction], zeros: Zeros) -> Vec<u128> {
+    let size = M as usize;
+    let mut offset = 0;
+
+    match zeros {
+        // after each turn the dial is `offset` away from its start, so it lands on 0 for exactly one start
+        Zeros::Landed => {
+            let mut landed = vec![0; size];
+            for instruction in instructions {
+                offset = turn(offset, instruction, size);
+                landed[(size - offset) % size] += 1;
+            }
+            landed
+        }
+        // full revolutions pass 0 from any start, the rest only from the starts within the clicks before 0
+        Zeros::Passed => {
+            let mut revolutions = 0;
+            let mut diff = vec![0i128; size + 1];
+
+            for instruction in instructions {
+                let clicks = instruction.clicks();
+                revolutions += clicks / size as u128;
+
+                let rest = (clicks % size as u128) as usize;
+                if rest > 0 {
+                    // positions from which the rest of the turn reaches 0
+                    let first = match instruction {
+                        Instruction::Left(_) => 1,
+                        Instruction::Right(_) => size - rest,
+                    };
+                    add_cyclic(&mut diff, (first + size - offset) % size, rest);
+                }
+
+                offset = turn(offset, instruction, size);
+            }
+
+            let mut passes = 0;
+            diff[..size]
+                .iter()
+                .map(|delta| {
+                    passes += delta;
+                    revolutions + passes as u128
+                })
+                .collect()
+        }
+    }
+}
+
+/// Start positions of a dial with `M` positions that end up with exactly `count` zeros.
+pub fn starts_with<const M: isize>(
+    instructions: &[Instruction],
+    zeros: Zeros,
+    count: u128,
+) -> Vec<isize> {
+    zeros_by_start::<M>(instructions, zeros)
+        .iter()
+        .enumerate()
+        .filter(|(_, zeros)| **zeros == count)
+        .map(|(start, _)| start as isize)
+        .collect()
+}
+
+/// Fewest instructions to replace so a dial with `M` positions starting at `start` ends up with exactly `count` zeros.
+///
+/// A replaced instruction may turn the dial any way, `None` if not even replacing every instruction is enough - which
+/// only happens when landing on 0 more often than there are instructions.
+///
+/// With `e` replacements every count between the fewest and the most zeros reachable is possible, as moving a
+/// replacement by one instruction changes the count by at most one. So this only tracks both bounds per position
+/// and number of replacements, in `O(instructions * M)` per replacement.
+pub fn min_edits<const M: isize>(
+    instructions: &[Instruction],
+    start: isize,
+    zeros: Zeros,
+    count: u128,
+) -> Option<usize> {
+    // every instruction lands on 0 at most once
+    if zeros == Zeros::Landed && count > instructions.len() as u128 {
+        return None;
+    }
+
+    let size = M as usize;
+    let start = start.rem_euclid(M) as usize;
+
+    // where each instruction takes the dial from every position, and the zeros on the way
+    let transitions: Vec<Vec<(usize, u128)>> = instructions
+        .iter()
+        .map(|instruction| {
+            (0..size)
+                .map(|position| step(position, instruction, size, zeros))
+                .collect()
+        })
+        .collect();
+
+    // fewest and most zeros before each instruction with one replacement less, on 0 and elsewhere
+    let mut previous: Option<Vec<(Bounds, Bounds)>> = None;
+    let mut current = vec![None; size];
+    let mut next = vec![None; size];
+
+    for edits in 0..=instructions.len() {
+        let mut before = Vec::with_capacity(instructions.len());
+        current.fill(None);
+        current[start] = Some((0, 0));
+
+        for (idx, transitions) in transitions.iter().enumerate() {
+            let from_other =
+                current[1..]
+                    .iter()
+                    .flatten()
+                    .fold(None, |mut bound, (fewest, most)| {
+                        merge(&mut bound, *fewest, *most);
+                        bound
+                    });
+            before.push((current[0], from_other));
+            next.fill(None);
+
+            for (value, (end, hits)) in current.iter().zip(transitions) {
+                let Some((fewest, most)) = value else {
+                    continue;
+                };
+                merge(&mut next[*end], fewest + hits, most.saturating_add(*hits));
+            }
+
+            // replacing this instruction moves the dial from any position to any other, and the zeros on the way
+            // only depend on whether it starts and ends on 0
+            if let Some(previous) = &previous {
+                let (from_zero, from_other) = previous[idx];
+
+                for (to, next) in next.iter_mut().enumerate() {
+                    for (from, bound) in [(true, from_zero), (false, from_other)] {
+                        let Some((fewest, most)) = bound else {
+                            continue;
+                        };
+                        let (fewest_hits, most_hits) = replaced(from, to == 0, zeros);
+                        merge(next, fewest + fewest_hits, most.saturating_add(most_hits));
+                    }
+                }
+            }
+
+            std::mem::swap(&mut current, &mut next);
+        }
+
+        let reachable = current
+            .iter()
+            .flatten()
+            .any(|(fewest, most)| (*fewest..=*most).contains(&count));
+        if reachable {
+            return Some(edits);
+        }
+
+        previous = Some(before);
+    }
+
+    None
+}
+
+/// Fewest and most zeros so far at a position, `None` if the dial can't be there.
+type Bounds = Option<(u128, u128)>;
+
+/// Zeros of a single replaced instruction as fewest and most, by whether it starts and ends on 0.
+fn replaced(from_zero: bool, to_zero: bool, zeros: Zeros) -> (u128, u128) {
+    match zeros {
+        Zeros::Landed => (to_zero as u128, to_zero as u128),
+        // one direction always avoids passing 0 on the way, and extra revolutions add as many passes as wanted
+        Zeros::Passed => ((to_zero && !from_zero) as u128, u128::MAX),
+    }
+}
+
+/// Position after turning and the zeros counted on the way.
+fn step(position: usize, instruction: &Instruction, size: usize, zeros: Zeros) -> (usize, u128) {
+    let end = turn(position, instruction, size);
+
+    let hits = match zeros {
+        Zeros::Landed => (end == 0) as u128,
+        Zeros::Passed => {
+            // the rest after full revolutions only reaches 0 if it is at least as far away
+            let rest = (instruction.clicks() % size as u128) as usize;
+            let reaches_zero = match instruction {
+                Instruction::Left(_) => position > 0 && rest >= position,
+                Instruction::Right(_) => position > 0 && rest >= size - position,
+            };
+            instruction.clicks() / size as u128 + reaches_zero as u128
+        }
+    };
+
+    (end, hits)
+}
+
+fn merge(bound: &mut Bounds, fewest: u128, most: u128) {
+    *bound = Some(match bound {
+        Some((old_fewest, old_most)) => ((*old_fewest).min(fewest), (*old_most).max(most)),
+        None => (fewest, most),
+    });
+}
+
+fn turn(offset: usize, instruction: &Instruction, size: usize) -> usize {
+    let clicks = (instruction.clicks() % size as u128) as usize;
+
+    match instruction {
+        Instruction::Left(_) => (offset + size - clicks) % size,
+        Instruction::Right(_) => (offset + clicks) % size,
+    }
+}
+
+/// Adds 1 to `len` positions starting at `first`, wrapping around the end of the dial.
+fn add_cyclic(diff: &mut [i128], first: usize, len: usize) {
+    let size = diff.len() - 1;
+    let end = first + len;
+
+    diff[first] += 1;
+    if end <= size {
+        diff[end] -= 1;
+    } else {
+        diff[size] -= 1;
+        diff[0] += 1;
+        diff[end - size] -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        Solution,
+        day01::{Day01, SafeDial},
+        xorshift,
+    };
+
+    /// Instructions from a reproducible xorshift stream, turning up to `max_clicks` clicks.
+    fn random_instructions(seed: u64, len: usize, max_clicks: u64) -> Vec<Instruction> {
+        let mut next = xorshift(seed);
+        (0..len)
+            .map(|_| {
+                let clicks = next(max_clicks + 1) as u128;
+                if next(2) == 0 {
+                    Instruction::Left(clicks)
+                } else {
+                    Instruction::Right(clicks)
+                }
+            })
+            .collect()
+    }
+
+    fn simulate<const M: isize>(instructions: &[Instruction], start: isize, zeros: Zeros) -> u128 {
+        let mut dial = SafeDial::<M>::new(start);
+        for instruction in instructions {
+            match zeros {
+                Zeros::Landed => dial.move_dial(instruction),
+                Zeros::Passed => dial.move_dial_with_count(instruction),
+            }
+        }
+        dial.count()
+    }
+
+    #[test]
+    fn test_zeros_by_start_like_simulation() {
+        for seed in 1..30 {
+            let instructions = random_instructions(seed * 7919, 40, 350);
+
+            for zeros in [Zeros::Landed, Zeros::Passed] {
+                let by_start = zeros_by_start::<100>(&instructions, zeros);
+                for start in 0..100 {
+                    assert_eq!(
+                        by_start[start as usize],
+                        simulate::<100>(&instructions, start, zeros)
+                    );
+                }
+
+                let by_start = zeros_by_start::<7>(&instructions, zeros);
+                for start in 0..7 {
+                    assert_eq!(
+                        by_start[start as usize],
+                        simulate::<7>(&instructions, start, zeros)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let instructions = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+
+        assert!(starts_with::<100>(&instructions, Zeros::Landed, 3).contains(&50));
+        assert!(starts_with::<100>(&instructions, Zeros::Passed, 6).contains(&50));
+        assert_eq!(
+            starts_with::<100>(&instructions, Zeros::Landed, 11),
+            Vec::<isize>::new()
+        );
+    }
+
+    /// Fewest replacements found by trying every replacement of up to two instructions.
+    fn brute_min_edits<const M: isize>(
+        instructions: &[Instruction],
+        start: isize,
+        zeros: Zeros,
+        count: u128,
+    ) -> Option<usize> {
+        let replacements: Vec<_> = (0..5 * M as u128)
+            .flat_map(|clicks| [Instruction::Left(clicks), Instruction::Right(clicks)])
+            .collect();
+
+        if simulate::<M>(instructions, start, zeros) == count {
+            return Some(0);
+        }
+
+        let mut edited = instructions.to_vec();
+        for first in 0..instructions.len() {
+            for replacement in &replacements {
+                edited[first] = *replacement;
+                if simulate::<M>(&edited, start, zeros) == count {
+                    return Some(1);
+                }
+            }
+            edited[first] = instructions[first];
+        }
+
+        for first in 0..instructions.len() {
+            for second in first + 1..instructions.len() {
+                for one in &replacements {
+                    for other in &replacements {
+                        edited[first] = *one;
+                        edited[second] = *other;
+                        if simulate::<M>(&edited, start, zeros) == count {
+                            return Some(2);
+                        }
+                    }
+                }
+                edited[first] = instructions[first];
+                edited[second] = instructions[second];
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn test_min_edits_like_brute_force() {
+        for seed in 1..12 {
+            let instructions = random_instructions(seed * 104_729, 5, 9);
+
+            for zeros in [Zeros::Landed, Zeros::Passed] {
+                for count in 0..=4 {
+                    let edits = min_edits::<4>(&instructions, 1, zeros, count);
+                    match brute_min_edits::<4>(&instructions, 1, zeros, count) {
+                        Some(brute) => {
+                            assert_eq!(edits, Some(brute), "{instructions:?} {zeros:?} {count}")
+                        }
+                        None => assert!(
+                            edits.is_none_or(|edits| edits > 2),
+                            "{instructions:?} {zeros:?} {count}"
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_edits() {
+        let instructions = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+
+        assert_eq!(
+            min_edits::<100>(&instructions, 50, Zeros::Landed, 3),
+            Some(0)
+        );
+        // none of the turns keeps the dial on 0, so every one of them has to change
+        assert_eq!(
+            min_edits::<100>(&instructions, 50, Zeros::Landed, 10),
+            Some(10)
+        );
+        assert_eq!(min_edits::<100>(&instructions, 50, Zeros::Landed, 11), None);
+
+        let input = Day01::parse(include_str!("../../assets/day01/input.txt")).unwrap();
+        assert_eq!(min_edits::<100>(&input, 50, Zeros::Landed, 5000), None);
+        assert_eq!(
+            min_edits::<100>(&instructions, 50, Zeros::Passed, 1000),
+            Some(1)
+        );
+        assert_eq!(
+            min_edits::<100>(&instructions, 50, Zeros::Passed, 0),
+            Some(3)
+        );
+    }
+}
@@ -1,37 +1,53 @@
 use std::ops::RangeInclusive;
 
-fn main() {
-    let input = include_str!("../../assets/day02/input.txt");
-    let ranges = parse(input);
-
-    let result: usize = ranges
-        .clone()
-        .into_iter()
-        .map(|range| range.get_symmetric_nums())
-        .flatten()
-        .sum();
-
-    println!("Part 1: {result}");
-    
-    let result2: usize = ranges
-        .into_iter()
-        .map(|range| range.get_repeating_nums())
-        .flatten()
-        .sum();
-    
-    println!("Part 2: {result2}");
-    
-}
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
 
-fn parse(input: &str) -> Vec<RangeInclusive<usize>> {
-    input
-        .split(',')
-        .flat_map(|range_str| {
-            let (first, second) = range_str.split_once('-').ok_or("invalid input")?;
-            let first = first.parse().map_err(|_| "invalid number")?;
-            let second = second.parse().map_err(|_| "invalid number")?;
+    type Input = Vec<RangeInclusive<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
 
-            Ok::<_, String>(RangeInclusive::new(first, second))
+    fn part1(ranges: &Self::Input) -> Answer {
+        ranges
+            .iter()
+            .cloned()
+            .flat_map(|range| range.get_symmetric_nums())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(ranges: &Self::Input) -> Answer {
+        ranges
+            .iter()
+            .cloned()
+            .flat_map(|range| range.get_repeating_nums())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<RangeInclusive<usize>>, ParseError> {
+    let line = input.trim();
+
+    line.split(',')
+        .map(|range_str| {
+            let (first, second) = range_str
+                .split_once('-')
+                .ok_or_else(|| ParseError::new(line, range_str, "expected range like 11-22"))?;
+            let first = first
+                .parse()
+                .map_err(|_| ParseError::new(line, first, "invalid number"))?;
+            let second = second
+                .parse()
+                .map_err(|_| ParseError::new(line, second, "invalid number"))?;
+
+            Ok(RangeInclusive::new(first, second))
         })
         .collect()
 }
@@ -67,22 +83,24 @@ impl ValidNums for RangeInclusive<usize> {
                 let mut is_repeating_pattern = false;
                 let max_pattern_size = num_len / 2;
                 let num_chars: Vec<_> = num_str.chars().collect();
-            
+
                 for pattern_size in 1..=max_pattern_size {
-                    let pattern = num_chars.chunks(pattern_size).next().expect("not empty"); 
-                    is_repeating_pattern = num_chars.chunks(pattern_size).skip(1).all(|other| other == pattern);
+                    let pattern = num_chars.chunks(pattern_size).next().expect("not empty");
+                    is_repeating_pattern = num_chars
+                        .chunks(pattern_size)
+                        .skip(1)
+                        .all(|other| other == pattern);
                     if is_repeating_pattern {
-                        break
+                        break;
                     }
                 }
-                
+
                 is_repeating_pattern
             })
             .collect()
     }
 }
 
-
 #[cfg(test)]
 mod tests {
 
@@ -92,7 +110,7 @@ mod tests {
     fn test_parser() {
         let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
 
-        let ranges = parse(input);
+        let ranges = parse(input).unwrap();
 
         assert_eq!(ranges[0], RangeInclusive::new(11, 22));
         assert_eq!(ranges[1], RangeInclusive::new(95, 115));
@@ -100,6 +118,9 @@ mod tests {
         assert_eq!(ranges[3], RangeInclusive::new(1188511880, 1188511890));
         assert_eq!(ranges[4], RangeInclusive::new(222220, 222224));
         assert_eq!(ranges[5], RangeInclusive::new(1698522, 1698528));
+
+        let err = parse("11-22,95_115,998-1012").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (7, "95_115"));
     }
 
     #[test]
@@ -126,14 +147,17 @@ mod tests {
             RangeInclusive::new(1698522, 1698528).get_symmetric_nums()
         );
     }
-    
+
     #[test]
     fn test_repeating_nums() {
         assert_eq!(
             vec![11, 22],
             RangeInclusive::new(11, 22).get_repeating_nums()
         );
-        assert_eq!(vec![99, 111], RangeInclusive::new(95, 115).get_repeating_nums());
+        assert_eq!(
+            vec![99, 111],
+            RangeInclusive::new(95, 115).get_repeating_nums()
+        );
         assert_eq!(
             vec![999, 1010],
             RangeInclusive::new(998, 1012).get_repeating_nums()
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Bank>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Bank::try_from(line).map_err(|err| err.at_line(idx)))
+            .collect()
+    }
+
+    fn part1(banks: &Self::Input) -> Answer {
+        banks
+            .iter()
+            .map(|bank| bank.find_max_two_digit_num())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(banks: &Self::Input) -> Answer {
+        banks
+            .iter()
+            .map(|bank| bank.find_max_twelve_digit_num())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[derive(PartialOrd, PartialEq, Eq, Ord, Debug, Clone, Copy)]
+struct Battery(u8, usize);
+
+pub struct Bank(Vec<Battery>);
+
+impl TryFrom<&str> for Bank {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let batteries = input
+            .char_indices()
+            .enumerate()
+            .map(|(idx, (offset, c))| {
+                let digit = &input[offset..offset + c.len_utf8()];
+                let num = digit
+                    .parse()
+                    .map_err(|_| ParseError::new(input, digit, "invalid number"))?;
+                Ok(Battery(num, idx))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Bank(batteries))
+    }
+}
+
+impl Bank {
+    fn find_max_two_digit_num(&self) -> usize {
+        let mut possible_nums = HashSet::<usize>::new();
+
+        for (idx, this) in self.0.iter().enumerate() {
+            for other_idx in idx + 1..self.0.len() {
+                let other = self.0.get(other_idx).expect("checked");
+
+                let first_num = this.0.to_string();
+                let second_num = other.0.to_string();
+                let num_str = format!("{first_num}{second_num}");
+                possible_nums.insert(num_str.parse().unwrap_or(0));
+            }
+        }
+
+        let result = possible_nums.iter().max().unwrap_or(&0);
+        *result
+    }
+
+    fn find_max_twelve_digit_num(&self) -> usize {
+        let mut idx_list = [0usize; 12];
+        let mut curr_pos = 0usize;
+        let mut skips = self.0.len() - 12;
+
+        for idx in idx_list.iter_mut() {
+            let slice = &self.0[curr_pos..=curr_pos + skips];
+            let max_val = slice
+                .iter()
+                .rev()
+                .max_by_key(|battery| battery.0)
+                .expect("no max value");
+            *idx = max_val.1;
+            skips -= max_val.1 - curr_pos;
+            curr_pos = max_val.1 + 1;
+        }
+
+        self.build_number_from_idx_list(&idx_list)
+    }
+
+    fn build_number_from_idx_list(&self, idx_list: &[usize; 12]) -> usize {
+        let mut nums = [0u8; 12];
+
+        for (i, idx) in idx_list.iter().enumerate() {
+            let num = self.0[*idx].0;
+            nums[i] = num;
+        }
+
+        nums.into_iter().fold(0, |acc, num| acc * 10 + num as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_find_max_in_bank() {
+        let bank: Bank = "987654321111111".try_into().unwrap();
+
+        assert_eq!(98, bank.find_max_two_digit_num());
+
+        let bank: Bank = "811111111111119".try_into().unwrap();
+        assert_eq!(89, bank.find_max_two_digit_num());
+
+        let bank: Bank = "234234234234278".try_into().unwrap();
+        assert_eq!(78, bank.find_max_two_digit_num());
+
+        let bank: Bank = "818181911112111".try_into().unwrap();
+        assert_eq!(92, bank.find_max_two_digit_num());
+    }
+
+    #[test]
+    fn test_max_12_digit_numbers() {
+        let bank: Bank = "987654321111111".try_into().unwrap();
+
+        assert_eq!(bank.find_max_twelve_digit_num(), 987654321111);
+
+        let bank: Bank = "811111111111119".try_into().unwrap();
+
+        assert_eq!(bank.find_max_twelve_digit_num(), 811111111119);
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.try_into()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        grid.get_removable().len().into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+        let mut total = 0;
+
+        loop {
+            let removable_points = grid.get_removable();
+            if removable_points.is_empty() {
+                break;
+            }
+            total += removable_points.len();
+            grid.remove(removable_points);
+        }
+
+        total.into()
+    }
+}
+
+#[derive(Clone)]
+pub struct Grid(HashSet<Point>);
+
+impl Grid {
+    fn get_removable(&self) -> Vec<Point> {
+        self.0
+            .iter()
+            .filter(|Point(x, y)| {
+                let left = Point(x - 1, *y);
+                let right = Point(x + 1, *y);
+                let top_left = Point(x - 1, y - 1);
+                let top = Point(*x, y - 1);
+                let top_right = Point(x + 1, y - 1);
+                let bottom_left = Point(x - 1, y + 1);
+                let bottom = Point(*x, y + 1);
+                let bottom_right = Point(x + 1, y + 1);
+                let neighbors = [
+                    left,
+                    right,
+                    top,
+                    bottom,
+                    top_left,
+                    top_right,
+                    bottom_right,
+                    bottom_left,
+                ];
+
+                let cnt_neighbors = neighbors
+                    .iter()
+                    .filter(|point| self.0.contains(point))
+                    .count();
+
+                cnt_neighbors < 4
+            })
+            .map(|point| Point(point.0, point.1))
+            .collect()
+    }
+
+    fn remove(&mut self, points: Vec<Point>) {
+        for point in points {
+            self.0.remove(&point);
+        }
+    }
+}
+
+impl TryFrom<&str> for Grid {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut points = HashSet::new();
+
+        for (row_idx, row) in value.lines().enumerate() {
+            for (col_idx, (offset, c)) in row.char_indices().enumerate() {
+                match c {
+                    '@' => {
+                        points.insert(Point(col_idx as isize, row_idx as isize));
+                    }
+                    '.' => (),
+                    _ => {
+                        let field = &row[offset..offset + c.len_utf8()];
+                        return Err(
+                            ParseError::new(row, field, "expected '@' or '.'").at_line(row_idx)
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(Self(points))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Point(isize, isize);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let grid = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+
+        let grid: Grid = grid.try_into().unwrap();
+
+        let result = grid.get_removable().len();
+
+        assert_eq!(13, result);
+    }
+
+    #[test]
+    fn test_part2() {
+        let grid = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+
+        let mut grid: Grid = grid.try_into().unwrap();
+        let mut total = 0;
+
+        loop {
+            let removable_points = grid.get_removable();
+            if removable_points.is_empty() {
+                break;
+            }
+            total += removable_points.len();
+            grid.remove(removable_points);
+        }
+
+        assert_eq!(43, total);
+    }
+}
@@ -1,39 +1,64 @@
 use std::ops::RangeInclusive;
 
-fn main() {
-    let input = include_str!("../../assets/day05/input.txt");
+use crate::{Answer, ParseError, Solution};
 
-    let (ranges, ids) = parse(input);
+pub struct Day05;
 
-    let part1 = solve_part1(&ranges, &ids);
+impl Solution for Day05 {
+    const DAY: u8 = 5;
 
-    println!("Part 1: {part1}");
+    type Input = (Vec<RangeInclusive<usize>>, Vec<usize>);
 
-    let part2 = solve_part2(ranges);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((ranges, ids): &Self::Input) -> Answer {
+        solve_part1(ranges, ids).into()
+    }
 
-    println!("Part 2: {part2}");
+    fn part2((ranges, _): &Self::Input) -> Answer {
+        solve_part2(ranges.clone()).into()
+    }
 }
 
-fn parse(input: &str) -> (Vec<RangeInclusive<usize>>, Vec<usize>) {
-    let (ranges, ids) = input.split_once("\n\n").expect("malformed input");
+fn parse(input: &str) -> Result<(Vec<RangeInclusive<usize>>, Vec<usize>), ParseError> {
+    let mut lines = input.lines().enumerate();
+    let mut ranges = Vec::new();
+    let mut ids = Vec::new();
 
-    let ranges: Vec<_> = ranges
-        .lines()
-        .flat_map(|line| line.split_once('-').ok_or("malformed range"))
-        .flat_map(|(first, second)| {
-            let first: usize = first.parse().map_err(|_| "invalid number")?;
-            let second = second.parse().map_err(|_| "invalid number")?;
+    // ranges until the first blank line
+    for (idx, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
 
-            Ok::<_, String>(RangeInclusive::new(first, second))
-        })
-        .collect();
+        let (first, second) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(line, line, "expected range like 3-5").at_line(idx))?;
+        let first: usize = first
+            .parse()
+            .map_err(|_| ParseError::new(line, first, "invalid number").at_line(idx))?;
+        let second = second
+            .parse()
+            .map_err(|_| ParseError::new(line, second, "invalid number").at_line(idx))?;
+
+        ranges.push(RangeInclusive::new(first, second));
+    }
+
+    // ingredient ids after it
+    for (idx, line) in lines {
+        let id = line
+            .parse()
+            .map_err(|_| ParseError::new(line, line, "invalid number").at_line(idx))?;
 
-    let ids = ids.lines().flat_map(|line| line.parse()).collect();
+        ids.push(id);
+    }
 
-    (ranges, ids)
+    Ok((ranges, ids))
 }
 
-fn solve_part1(ranges: &Vec<RangeInclusive<usize>>, ids: &Vec<usize>) -> usize {
+fn solve_part1(ranges: &[RangeInclusive<usize>], ids: &[usize]) -> usize {
     ids.iter()
         .filter(|num| ranges.iter().any(|range| range.contains(num)))
         .count()
@@ -67,7 +92,7 @@ fn solve_part2(ranges: Vec<RangeInclusive<usize>>) -> usize {
         return 0;
     };
 
-    while let Some(next) = ranges.next() {
+    for next in ranges {
         // if current overlaps with next - merge
         if let Some(merged) = current.merge(&next) {
             current = merged;
@@ -92,7 +117,7 @@ mod tests {
     #[test]
     fn test_parsing() {
         let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-        let (ranges, ids) = parse(input);
+        let (ranges, ids) = parse(input).unwrap();
 
         assert_eq!(ranges[0], RangeInclusive::new(3, 5));
         assert_eq!(ranges[1], RangeInclusive::new(10, 14));
@@ -100,12 +125,15 @@ mod tests {
         assert_eq!(ranges[2], RangeInclusive::new(16, 20));
 
         assert_eq!(ids, vec![1, 5, 8, 11, 17, 32]);
+
+        let err = parse("3-5\n10-14\n\n1\n5a").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (5, 1, "5a"));
     }
 
     #[test]
     fn test_part1() {
         let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-        let (ranges, ids) = parse(input);
+        let (ranges, ids) = parse(input).unwrap();
 
         let result = solve_part1(&ranges, &ids);
 
@@ -115,7 +143,7 @@ mod tests {
     #[test]
     fn test_part2() {
         let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-        let (ranges, _) = parse(input);
+        let (ranges, _) = parse(input).unwrap();
 
         let result = solve_part2(ranges);
 
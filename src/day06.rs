@@ -1,25 +1,50 @@
 use std::collections::HashMap;
 
-fn main() {
-    let input = include_str!("../../assets/day06/input.txt");
-
-    let (numbers, operations) = parse(input);
-    let part1 = solve(numbers, operations);
-
-    println!("Part 1: {part1}");
-    
-    let operations = parse_operations(input);
-    let rows = parse_matrix(input);
-    let cols = transpose(rows);
-    let col_nums = parse_transposed_nums(cols);
-    let col_nums = col_nums.iter().rev().enumerate().fold(HashMap::new(), |mut map, (idx, val)| {
-        map.insert(idx, val.clone());
-        map
-    });
-    
-    let part2 = solve(col_nums, operations);
-    println!("Part 2: {part2}");
-    
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (numbers, operations) = parse(input)?;
+
+        let rows = parse_matrix(input);
+        let cols = transpose(rows);
+        let col_nums = parse_transposed_nums(cols);
+        let col_nums =
+            col_nums
+                .iter()
+                .rev()
+                .enumerate()
+                .fold(HashMap::new(), |mut map, (idx, val)| {
+                    map.insert(idx, val.clone());
+                    map
+                });
+
+        Ok(Worksheet {
+            numbers,
+            col_nums,
+            operations,
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> Answer {
+        solve(&worksheet.numbers, &worksheet.operations).into()
+    }
+
+    fn part2(worksheet: &Self::Input) -> Answer {
+        solve(&worksheet.col_nums, &worksheet.operations).into()
+    }
+}
+
+pub struct Worksheet {
+    numbers: HashMap<usize, Vec<usize>>,
+    col_nums: HashMap<usize, Vec<usize>>,
+    operations: HashMap<usize, Operation>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -28,22 +53,23 @@ enum Operation {
     Multiply,
 }
 
-fn parse_operations(input: &str) -> HashMap<usize, Operation> {
-    input
+fn parse_operations(input: &str) -> Result<HashMap<usize, Operation>, ParseError> {
+    let line_idx = input.lines().count().saturating_sub(1);
+    let line = input
         .lines()
-        .rev()
-        .next()
-        .expect("invalid input - empty")
-        .split_ascii_whitespace()
-        .flat_map(|c| match c {
+        .next_back()
+        .ok_or_else(|| ParseError::new("", "", "empty input"))?;
+
+    line.split_ascii_whitespace()
+        .map(|c| match c {
             "+" => Ok(Operation::Add),
             "*" => Ok(Operation::Multiply),
-            _ => Err("invalid input"),
+            _ => Err(ParseError::new(line, c, "expected '+' or '*'").at_line(line_idx)),
         })
         .enumerate()
-        .fold(HashMap::new(), |mut map, (idx, op)| {
-            map.insert(idx, op);
-            map
+        .try_fold(HashMap::new(), |mut map, (idx, op)| {
+            map.insert(idx, op?);
+            Ok(map)
         })
 }
 
@@ -55,31 +81,59 @@ fn parse_matrix(input: &str) -> Vec<Vec<char>> {
         .collect()
 }
 
-fn parse(input: &str) -> (HashMap<usize, Vec<usize>>, HashMap<usize, Operation>) {
-    let operations = parse_operations(input);
+type Numbers = HashMap<usize, Vec<usize>>;
+type Operations = HashMap<usize, Operation>;
+
+fn parse(input: &str) -> Result<(Numbers, Operations), ParseError> {
+    let operations = parse_operations(input)?;
+    let number_rows = input.lines().count() - 1;
+
+    if number_rows == 0 {
+        let line = input.lines().next().unwrap_or_default();
+        let msg = "expected at least one row of numbers above the operations";
+        return Err(ParseError::new(line, &line[..0], msg));
+    }
 
     let numbers = input
         .lines()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .flat_map(|num_str| num_str.parse::<usize>())
-                .collect::<Vec<_>>()
+        .enumerate()
+        .take(number_rows)
+        .map(|(line_idx, line)| {
+            let num_vec = line
+                .split_ascii_whitespace()
+                .map(|num_str| {
+                    num_str.parse::<usize>().map_err(|_| {
+                        ParseError::new(line, num_str, "invalid number").at_line(line_idx)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if num_vec.len() != operations.len() {
+                let message = format!(
+                    "expected {} numbers, found {}",
+                    operations.len(),
+                    num_vec.len()
+                );
+                return Err(ParseError::new(line, &line[line.len()..], message).at_line(line_idx));
+            }
+
+            Ok(num_vec)
         })
-        .fold(
+        .try_fold(
             HashMap::new(),
             |mut num_map: HashMap<usize, Vec<usize>>, num_vec| {
-                num_vec.into_iter().enumerate().for_each(|(idx, num)| {
+                num_vec?.into_iter().enumerate().for_each(|(idx, num)| {
                     if let Some(numbers) = num_map.get_mut(&idx) {
                         numbers.push(num);
                     } else {
                         num_map.insert(idx, vec![num]);
                     }
                 });
-                num_map
+                Ok(num_map)
             },
-        );
+        )?;
 
-    (numbers, operations)
+    Ok((numbers, operations))
 }
 
 fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
@@ -107,7 +161,7 @@ fn parse_transposed_nums(transposed_nums: Vec<Vec<char>>) -> Vec<Vec<usize>> {
         if col.iter().all(|c| c.is_whitespace()) {
             cols.push(current.clone());
             current.clear();
-            continue
+            continue;
         }
 
         let num: usize = col
@@ -117,28 +171,19 @@ fn parse_transposed_nums(transposed_nums: Vec<Vec<char>>) -> Vec<Vec<usize>> {
 
         current.push(num);
     }
-    
+
     cols.push(current.clone());
 
     cols
 }
 
-fn solve(
-    numbers: HashMap<usize, Vec<usize>>,
-    operations: HashMap<usize, Operation>,
-) -> usize {
+fn solve(numbers: &HashMap<usize, Vec<usize>>, operations: &HashMap<usize, Operation>) -> usize {
     numbers
-        .keys()
-        .flat_map(|idx| match operations.get(idx) {
+        .iter()
+        .flat_map(|(idx, nums)| match operations.get(idx) {
             None => Err("invalid input"),
-            Some(op) => match op {
-                Operation::Add => Ok(numbers.get(idx).ok_or("invalid input")?.iter().sum::<usize>()),
-                Operation::Multiply => Ok(numbers
-                    .get(idx)
-                    .ok_or("invalid_input")?
-                    .iter()
-                    .product())
-            },
+            Some(Operation::Add) => Ok(nums.iter().sum::<usize>()),
+            Some(Operation::Multiply) => Ok(nums.iter().product()),
         })
         .sum()
 }
@@ -150,7 +195,7 @@ mod tests {
     #[test]
     fn test_parsing() {
         let input = "123 328  51 64\n45 64  387 23\n6 98  215 314\n*   +   *   +  ";
-        let (numbers, operations) = parse(input);
+        let (numbers, operations) = parse(input).unwrap();
 
         assert_eq!(numbers.get(&0), Some(&vec![123, 45, 6]));
         assert_eq!(numbers.get(&1), Some(&vec![328, 64, 98]));
@@ -161,13 +206,22 @@ mod tests {
         assert_eq!(operations.get(&1), Some(&Operation::Add));
         assert_eq!(operations.get(&2), Some(&Operation::Multiply));
         assert_eq!(operations.get(&3), Some(&Operation::Add));
+
+        let err = parse("123 328\n45 6x4\n*   +").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "6x4"));
+
+        let err = parse("123 328\n45 64\n*   -").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 5, "-"));
+
+        let err = Day06::parse("+ *\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
     }
 
     #[test]
     fn test_solve_part1() {
         let input = "123 328  51 64\n45 64  387 23\n6 98  215 314\n*   +   *   +";
-        let (numbers, operations) = parse(input);
-        let part1 = solve(numbers, operations);
+        let (numbers, operations) = parse(input).unwrap();
+        let part1 = solve(&numbers, &operations);
         assert_eq!(4277556, part1);
     }
 
@@ -193,14 +247,14 @@ mod tests {
         assert_eq!(cols[13], vec!['4', '3', '1']);
         assert_eq!(cols[14], vec![' ', '4']);
     }
-    
+
     #[test]
     fn test_parse_transposed_nums() {
         let input = "123 328  51 64 \n 45 64  387 23\n  6 98  215 314\n*   +   *   +  ";
         let rows = parse_matrix(input);
         let cols = transpose(rows);
         let cols = parse_transposed_nums(cols);
-        
+
         assert_eq!(cols[0], vec![4, 431, 623]);
         assert_eq!(cols[1], vec![175, 581, 32]);
         assert_eq!(cols[2], vec![8, 248, 369]);
@@ -210,16 +264,21 @@ mod tests {
     #[test]
     fn test_solve_part2() {
         let input = "123 328  51 64 \n 45 64  387 23\n  6 98  215 314\n*   +   *   +  ";
-        let operations = parse_operations(input);
+        let operations = parse_operations(input).unwrap();
         let rows = parse_matrix(input);
         let cols = transpose(rows);
         let col_nums = parse_transposed_nums(cols);
-        let col_nums = col_nums.iter().rev().enumerate().fold(HashMap::new(), |mut map, (idx, val)| {
-            map.insert(idx, val.clone());
-            map
-        });
+        let col_nums =
+            col_nums
+                .iter()
+                .rev()
+                .enumerate()
+                .fold(HashMap::new(), |mut map, (idx, val)| {
+                    map.insert(idx, val.clone());
+                    map
+                });
 
-        let result = solve(col_nums, operations);
+        let result = solve(&col_nums, &operations);
 
         assert_eq!(3263827, result);
     }
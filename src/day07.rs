@@ -0,0 +1,827 @@
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        if grid.is_classic() {
+            grid.sweep().splits.into()
+        } else {
+            grid.trace().splits.into()
+        }
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        // timelines are only defined for beams travelling down without mirrors
+        if !grid.is_classic() {
+            return Answer::Unimplemented;
+        }
+
+        match grid.sweep().timelines {
+            Some(timelines) => timelines.into(),
+            None => Answer::Unsolvable("more timelines than fit into 128 bits".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Field {
+    /// Start of a beam travelling in the given direction.
+    Beam(Direction),
+    /// Splits vertical beams into two beams continuing next to it.
+    Splitter,
+    /// Splits vertical beams into a beam travelling left and one travelling right.
+    HorizontalSplitter,
+    /// `/` mirror.
+    Mirror,
+    /// `\` mirror.
+    BackMirror,
+    Absorber,
+    Empty,
+}
+
+impl Field {
+    fn is_splitter(self) -> bool {
+        matches!(self, Field::Splitter | Field::HorizontalSplitter)
+    }
+}
+
+impl TryFrom<char> for Field {
+    type Error = String;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Field::Beam(Direction::Down)),
+            'N' => Ok(Field::Beam(Direction::Up)),
+            'E' => Ok(Field::Beam(Direction::Right)),
+            'W' => Ok(Field::Beam(Direction::Left)),
+            '^' => Ok(Field::Splitter),
+            '-' => Ok(Field::HorizontalSplitter),
+            '/' => Ok(Field::Mirror),
+            '\\' => Ok(Field::BackMirror),
+            '#' => Ok(Field::Absorber),
+            '.' => Ok(Field::Empty),
+            _ => Err(
+                "expected one of 'S', 'N', 'E', 'W', '^', '-', '/', '\\', '#' or '.'".to_string(),
+            ),
+        }
+    }
+}
+
+impl From<Field> for char {
+    fn from(value: Field) -> Self {
+        match value {
+            Field::Beam(Direction::Down) => 'S',
+            Field::Beam(Direction::Up) => 'N',
+            Field::Beam(Direction::Right) => 'E',
+            Field::Beam(Direction::Left) => 'W',
+            Field::Splitter => '^',
+            Field::HorizontalSplitter => '-',
+            Field::Mirror => '/',
+            Field::BackMirror => '\\',
+            Field::Absorber => '#',
+            Field::Empty => '.',
+        }
+    }
+}
+
+/// Dense row-major grid of fields.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    fields: Vec<Field>,
+}
+
+/// Outcome of sweeping the beams once from the top to the bottom of the grid.
+#[derive(Debug, PartialEq, Eq)]
+struct Sweep {
+    /// Splitters hit by a beam - beams merging in a column only split once.
+    splits: usize,
+    /// Timelines of a single particle that takes both ways at every splitter, `None` if more than fit into a `u128`.
+    timelines: Option<u128>,
+}
+
+/// Splitters hit by the beams and those never reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Beams arriving at each splitter that was hit. Every start and every split sends out new beams, which still
+    /// count separately once they merge on the way - a splitter hit by the merged beams of two splits has 2 arrivals.
+    pub arrivals: HashMap<Coord, usize>,
+    /// Splitters no beam reaches, in reading order.
+    pub unreachable: Vec<Coord>,
+}
+
+impl Coverage {
+    /// Renders the grid with unreachable splitters marked as `x`.
+    pub fn render(&self, grid: &Grid) -> String {
+        let mut rendered = String::with_capacity((grid.width + 1) * grid.height);
+
+        for y in 0..grid.height {
+            for (x, field) in grid.row(y).iter().enumerate() {
+                if field.is_splitter() && !self.arrivals.contains_key(&Coord(x, y)) {
+                    rendered.push('x');
+                } else {
+                    rendered.push((*field).into());
+                }
+            }
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+}
+
+/// Outcome of tracing beams in all directions through mirrors and splitters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// Cells a beam passed through, including where beams start, end or get absorbed.
+    pub energized: HashSet<Coord>,
+    /// Distinct splitters hit per direction - beams merging only split once.
+    pub splits: usize,
+    /// Whether a beam ended up circling, e.g. between mirrors.
+    pub looped: bool,
+}
+
+impl Trace {
+    /// Renders the grid with energized empty cells marked as `*`.
+    pub fn render(&self, grid: &Grid) -> String {
+        let mut rendered = String::with_capacity((grid.width + 1) * grid.height);
+
+        for y in 0..grid.height {
+            for (x, field) in grid.row(y).iter().enumerate() {
+                if *field == Field::Empty && self.energized.contains(&Coord(x, y)) {
+                    rendered.push('*');
+                } else {
+                    rendered.push((*field).into());
+                }
+            }
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+}
+
+impl Grid {
+    fn get(&self, coord: Coord) -> Option<Field> {
+        if coord.0 >= self.width || coord.1 >= self.height {
+            return None;
+        }
+
+        Some(self.fields[coord.1 * self.width + coord.0])
+    }
+
+    /// Coordinate next to `coord` in `direction`, if it is still within the grid.
+    fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        let Coord(x, y) = coord;
+
+        let next = match direction {
+            Direction::Up => Coord(x, y.checked_sub(1)?),
+            Direction::Down => Coord(x, y + 1),
+            Direction::Left => Coord(x.checked_sub(1)?, y),
+            Direction::Right => Coord(x + 1, y),
+        };
+
+        self.get(next).map(|_| next)
+    }
+
+    /// Whether the grid only holds beams travelling down and `^` splitters, as in the puzzle.
+    fn is_classic(&self) -> bool {
+        self.fields.iter().all(|field| {
+            matches!(
+                field,
+                Field::Beam(Direction::Down) | Field::Splitter | Field::Empty
+            )
+        })
+    }
+
+    fn row(&self, y: usize) -> &[Field] {
+        &self.fields[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Carries the number of timelines in each column down row by row - a column holds a beam as long as it has at
+    /// least one timeline. A timeline ends once its beam leaves the grid at the bottom or the sides.
+    fn sweep(&self) -> Sweep {
+        self.sweep_with(|_, _| ())
+    }
+
+    /// Like [`Grid::sweep`], but calls `on_split` with every splitter hit and the beams arriving there, see
+    /// [`Coverage::arrivals`].
+    fn sweep_with(&self, mut on_split: impl FnMut(Coord, usize)) -> Sweep {
+        // a column without timelines is `Some(0)`, `None` still holds a beam but too many timelines to count
+        let mut timelines = vec![Some(0u128); self.width];
+        let mut next = vec![Some(0u128); self.width];
+        let mut beams = vec![0; self.width];
+        let mut next_beams = vec![0; self.width];
+        let mut sweep = Sweep {
+            splits: 0,
+            timelines: Some(0),
+        };
+
+        for y in 0..self.height {
+            // new beams enter at their start position
+            for (x, field) in self.row(y).iter().enumerate() {
+                if *field == Field::Beam(Direction::Down) {
+                    add_timelines(&mut timelines[x], Some(1));
+                    beams[x] += 1;
+                }
+            }
+
+            let below = (y + 1 < self.height).then(|| self.row(y + 1));
+            next.fill(Some(0));
+            next_beams.fill(0);
+
+            for (x, &count) in timelines.iter().enumerate() {
+                if count == Some(0) {
+                    continue;
+                }
+
+                match below.map(|row| row[x]) {
+                    Some(Field::Splitter) => {
+                        sweep.splits += 1;
+                        on_split(Coord(x, y + 1), beams[x]);
+
+                        for split_x in [x.checked_sub(1), Some(x + 1).filter(|x| *x < self.width)] {
+                            match split_x {
+                                Some(split_x) => {
+                                    add_timelines(&mut next[split_x], count);
+                                    next_beams[split_x] += 1;
+                                }
+                                None => add_timelines(&mut sweep.timelines, count),
+                            }
+                        }
+                    }
+                    Some(_) => {
+                        add_timelines(&mut next[x], count);
+                        next_beams[x] += beams[x];
+                    }
+                    None => add_timelines(&mut sweep.timelines, count),
+                }
+            }
+
+            std::mem::swap(&mut timelines, &mut next);
+            std::mem::swap(&mut beams, &mut next_beams);
+        }
+
+        sweep
+    }
+
+    /// Which `^` and `-` splitters the beams hit, following them like part 1 - by the row sweep if the grid only
+    /// holds beams travelling down and `^` splitters, otherwise by [`Grid::trace`].
+    pub fn coverage(&self) -> Coverage {
+        let arrivals = if self.is_classic() {
+            let mut arrivals = HashMap::new();
+            self.sweep_with(|coord, beams| {
+                arrivals.insert(coord, beams);
+            });
+            arrivals
+        } else {
+            self.trace_with_arrivals().1
+        };
+
+        let unreachable = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.is_splitter())
+            .map(|(idx, _)| Coord(idx % self.width, idx / self.width))
+            .filter(|coord| !arrivals.contains_key(coord))
+            .collect();
+
+        Coverage {
+            arrivals,
+            unreachable,
+        }
+    }
+
+    /// Follows every beam through the grid, in any direction.
+    ///
+    /// A beam travelling into a `^` splitter continues from both cells next to it, while `-` sends it left and right
+    /// from the splitter itself - beams travelling along a splitter pass through. Every beam state (cell and direction)
+    /// is only followed once, so beams merging or circling between mirrors terminate.
+    pub fn trace(&self) -> Trace {
+        self.trace_with_arrivals().0
+    }
+
+    /// Like [`Grid::trace`], but also counts the beams arriving at every splitter hit, see [`Coverage::arrivals`].
+    fn trace_with_arrivals(&self) -> (Trace, HashMap<Coord, usize>) {
+        let mut energized = HashSet::new();
+        let mut split_hits = HashSet::new();
+        let mut looped = false;
+
+        // depth first, so a beam reaching a state on its own path is circling
+        let mut steps: HashMap<State, Step> = HashMap::new();
+        let mut on_path = HashSet::new();
+
+        let starts: Vec<State> = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, field)| match field {
+                Field::Beam(direction) => {
+                    Some((Coord(idx % self.width, idx / self.width), *direction))
+                }
+                _ => None,
+            })
+            .collect();
+
+        for start in &starts {
+            if steps.contains_key(start) {
+                continue;
+            }
+            let step = self.step_beam(*start);
+            let mut stack = vec![(*start, step.states().into_iter())];
+            steps.insert(*start, step);
+            on_path.insert(*start);
+
+            while let Some((state, next_states)) = stack.last_mut() {
+                energized.insert(state.0);
+
+                match next_states.next() {
+                    Some(next) if on_path.contains(&next) => looped = true,
+                    Some(next) => {
+                        if let Entry::Vacant(entry) = steps.entry(next) {
+                            let step = entry.insert(self.step_beam(next));
+                            stack.push((next, step.states().into_iter()));
+                            on_path.insert(next);
+                        }
+                    }
+                    None => {
+                        on_path.remove(state);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        // every start and every split sends out a beam, which travels on until it hits a splitter
+        let mut arrivals = HashMap::new();
+        let mut ends = HashMap::new();
+        let mut beams = starts;
+
+        for step in steps.values() {
+            if let Step::Split(hits, states) = step {
+                split_hits.extend(hits.iter().copied());
+                // the beams split off by a `^` into a `-` splitter next to it hit that one right away
+                for (coord, _) in &hits[1..] {
+                    *arrivals.entry(*coord).or_insert(0) += 1;
+                }
+                beams.extend(states.iter().copied());
+            }
+        }
+
+        for beam in beams {
+            if let Some(Step::Split(hits, _)) =
+                beam_end(beam, &steps, &mut ends).map(|end| &steps[&end])
+            {
+                *arrivals.entry(hits[0].0).or_insert(0) += 1;
+            }
+        }
+
+        let trace = Trace {
+            energized,
+            splits: split_hits.len(),
+            looped,
+        };
+
+        (trace, arrivals)
+    }
+
+    /// Where a beam in `state` goes within one step.
+    fn step_beam(&self, (coord, direction): State) -> Step {
+        if self.get(coord) == Some(Field::Absorber) {
+            return Step::End;
+        }
+
+        let Some(next) = self.step(coord, direction) else {
+            return Step::End;
+        };
+
+        match self.get(next).unwrap_or(Field::Empty) {
+            Field::Splitter if direction.is_vertical() => {
+                let mut hits = vec![(next, direction)];
+                let mut states = Vec::new();
+
+                // the cells next to the splitter turn or split the beams placed on them, a beam landing on another
+                // `^` continues through it as in the row sweep
+                for side in [Direction::Left, Direction::Right] {
+                    let Some(side) = self.step(next, side) else {
+                        continue;
+                    };
+
+                    match self.enter(side, direction) {
+                        Step::Split(side_hits, side_states) => {
+                            hits.extend(side_hits);
+                            states.extend(side_states);
+                        }
+                        step => states.extend(step.states()),
+                    }
+                }
+
+                Step::Split(hits, states)
+            }
+            _ => self.enter(next, direction),
+        }
+    }
+
+    /// Where a beam travelling into `coord` in `direction` goes after a mirror or `-` splitter there turned or split
+    /// it. A `^` splitter is only handled by [`Grid::step_beam`], as it places the beams next to itself.
+    fn enter(&self, coord: Coord, direction: Direction) -> Step {
+        match self.get(coord).unwrap_or(Field::Empty) {
+            Field::HorizontalSplitter if direction.is_vertical() => Step::Split(
+                vec![(coord, direction)],
+                vec![(coord, Direction::Left), (coord, Direction::Right)],
+            ),
+            Field::Mirror => {
+                let direction = match direction {
+                    Direction::Up => Direction::Right,
+                    Direction::Down => Direction::Left,
+                    Direction::Left => Direction::Down,
+                    Direction::Right => Direction::Up,
+                };
+                Step::Move((coord, direction))
+            }
+            Field::BackMirror => {
+                let direction = match direction {
+                    Direction::Up => Direction::Left,
+                    Direction::Down => Direction::Right,
+                    Direction::Left => Direction::Up,
+                    Direction::Right => Direction::Down,
+                };
+                Step::Move((coord, direction))
+            }
+            _ => Step::Move((coord, direction)),
+        }
+    }
+}
+
+/// State in which a beam starting in `state` hits a splitter, `None` if it leaves the grid, gets absorbed or
+/// circles forever before. Remembers the end of every state on the way in `ends`.
+fn beam_end(
+    state: State,
+    steps: &HashMap<State, Step>,
+    ends: &mut HashMap<State, Option<State>>,
+) -> Option<State> {
+    let mut path = Vec::new();
+    let mut on_path = HashSet::new();
+    let mut current = state;
+
+    let end = loop {
+        if let Some(end) = ends.get(&current) {
+            break *end;
+        }
+        if !on_path.insert(current) {
+            break None;
+        }
+        path.push(current);
+
+        match &steps[&current] {
+            Step::End => break None,
+            Step::Move(next) => current = *next,
+            Step::Split(..) => break Some(current),
+        }
+    };
+
+    for state in path {
+        ends.insert(state, end);
+    }
+
+    end
+}
+
+/// A beam in a cell, travelling in a direction.
+type State = (Coord, Direction);
+
+/// Where a beam goes within one step.
+#[derive(Debug)]
+enum Step {
+    /// The beam leaves the grid or gets absorbed.
+    End,
+    /// The beam continues in a single state, maybe turned by a mirror.
+    Move(State),
+    /// The beam hits the first splitter and continues in the split off states. A `^` may send the beams it splits
+    /// into `-` splitters next to it, which follow as further hits.
+    Split(Vec<State>, Vec<State>),
+}
+
+impl Step {
+    fn states(&self) -> Vec<State> {
+        match self {
+            Step::End => Vec::new(),
+            Step::Move(state) => vec![*state],
+            Step::Split(_, states) => states.clone(),
+        }
+    }
+}
+
+/// Adds `count` timelines to `total`, which stays `None` once either is too large to count.
+fn add_timelines(total: &mut Option<u128>, count: Option<u128>) {
+    *total = total
+        .zip(count)
+        .and_then(|(total, count)| total.checked_add(count));
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Coord(pub usize, pub usize);
+
+fn parse_grid(input: &str) -> Result<Grid, ParseError> {
+    let mut fields = Vec::with_capacity(input.len());
+    let mut width = None;
+    let mut height = 0;
+
+    for (y_idx, row) in input.lines().enumerate() {
+        let row_width = row.chars().count();
+        let expected_width = *width.get_or_insert(row_width);
+
+        if row_width != expected_width {
+            let msg = format!("expected {expected_width} fields like the first row");
+            return Err(ParseError::new(row, row, msg).at_line(y_idx));
+        }
+
+        for (offset, c) in row.char_indices() {
+            let field: Field = c.try_into().map_err(|err| {
+                ParseError::new(row, &row[offset..offset + c.len_utf8()], err).at_line(y_idx)
+            })?;
+            fields.push(field);
+        }
+
+        height += 1;
+    }
+
+    Ok(Grid {
+        width: width.unwrap_or(0),
+        height,
+        fields,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::examples::strip_header;
+
+    #[test]
+    fn test_parsing() {
+        let input = "...S...\n...^...\n..^.^..";
+
+        let grid = parse_grid(input).unwrap();
+
+        assert_eq!((grid.width, grid.height), (7, 3));
+
+        // first row
+        assert_eq!(grid.get(Coord(0, 0)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(1, 0)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(2, 0)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(3, 0)), Some(Field::Beam(Direction::Down)));
+        assert_eq!(grid.get(Coord(4, 0)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(5, 0)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(6, 0)), Some(Field::Empty));
+
+        // second row
+        assert_eq!(grid.get(Coord(0, 1)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(1, 1)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(2, 1)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(3, 1)), Some(Field::Splitter));
+        assert_eq!(grid.get(Coord(4, 1)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(5, 1)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(6, 1)), Some(Field::Empty));
+
+        // third row
+        assert_eq!(grid.get(Coord(0, 2)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(1, 2)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(2, 2)), Some(Field::Splitter));
+        assert_eq!(grid.get(Coord(3, 2)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(4, 2)), Some(Field::Splitter));
+        assert_eq!(grid.get(Coord(5, 2)), Some(Field::Empty));
+        assert_eq!(grid.get(Coord(6, 2)), Some(Field::Empty));
+
+        assert_eq!(grid.get(Coord(7, 0)), None);
+        assert_eq!(grid.get(Coord(0, 3)), None);
+
+        let err = parse_grid("...S...\n...v...").err().unwrap();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "v"));
+
+        let err = parse_grid("...S...\n...^..").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_sweep() {
+        let grid = parse_grid("...S...\n...^...\n..^.^..").unwrap();
+
+        // the beam splits at all three splitters, the two middle beams merge again
+        assert_eq!(
+            grid.sweep(),
+            Sweep {
+                splits: 3,
+                timelines: Some(4)
+            }
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = strip_header(include_str!("../assets/day07/test.txt"));
+
+        let grid = parse_grid(input).unwrap();
+
+        assert_eq!(21, grid.sweep().splits);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = strip_header(include_str!("../assets/day07/test.txt"));
+
+        let grid = parse_grid(input).unwrap();
+
+        assert_eq!(grid.sweep().timelines, Some(40));
+
+        // a beam leaving the grid at a side ends its timeline
+        let grid = parse_grid("S..\n^..\n.^.").unwrap();
+        assert_eq!(grid.sweep().timelines, Some(3));
+
+        // every row of splitters doubles the timelines, like Pascal's triangle
+        let pascal = |rows: usize| {
+            let mut input = format!("{}S{}\n", ".".repeat(rows), ".".repeat(rows));
+            for row in 0..rows {
+                let margin = ".".repeat(rows - row);
+                let splitters = vec!["^"; row + 1].join(".");
+                input.push_str(&format!("{margin}{splitters}{margin}\n"));
+            }
+            parse_grid(&input).unwrap()
+        };
+        assert_eq!(pascal(127).sweep().timelines, Some(1 << 127));
+        assert_eq!(pascal(128).sweep().timelines, None);
+        assert_eq!(
+            Day07::part2(&pascal(200)).to_string(),
+            "unsolvable: more timelines than fit into 128 bits"
+        );
+    }
+
+    #[test]
+    fn test_trace_classic() {
+        let input = strip_header(include_str!("../assets/day07/test.txt"));
+
+        let grid = parse_grid(input).unwrap();
+        let trace = grid.trace();
+
+        assert_eq!(trace.splits, grid.sweep().splits);
+        assert!(!trace.looped);
+
+        // splitters next to each other only split a beam travelling into them, not one landing on them
+        let grid = parse_grid("..S..\n.^^^.\n..^..\n.^.^.").unwrap();
+        assert_eq!(grid.trace().splits, grid.sweep().splits);
+        assert_eq!(grid.sweep().splits, 3);
+
+        // an unreachable absorber makes no difference
+        let classic = parse_grid(".S..\n.^^.\n....").unwrap();
+        let extended = parse_grid(".S..#\n.^^..\n.....").unwrap();
+        assert_eq!(Day07::part1(&classic), Day07::part1(&extended));
+    }
+
+    #[test]
+    fn test_trace_mirror_loop() {
+        let grid = parse_grid("/.E\\\n....\n\\../").unwrap();
+
+        let trace = grid.trace();
+
+        assert!(trace.looped);
+        assert_eq!(trace.splits, 0);
+        assert_eq!(trace.energized.len(), 10);
+        assert_eq!(trace.render(&grid), "/*E\\\n*..*\n\\**/\n");
+    }
+
+    #[test]
+    fn test_trace_splitters_and_absorbers() {
+        let grid = parse_grid("..S..\n#.-..\n.....\n#..W.").unwrap();
+
+        let trace = grid.trace();
+
+        assert!(!trace.looped);
+        assert_eq!(trace.splits, 1);
+        assert_eq!(trace.render(&grid), "..S..\n#*-**\n.....\n#**W.\n");
+        assert!(trace.energized.contains(&Coord(0, 1)));
+        assert!(trace.energized.contains(&Coord(0, 3)));
+
+        assert_eq!(Day07::part1(&grid), Answer::from(1usize));
+        assert_eq!(Day07::part2(&grid), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn test_trace_split_into_fields() {
+        // the right beam is turned by the mirror next to the splitter
+        let grid = parse_grid(".S..\n.^\\.\n....").unwrap();
+        let trace = grid.trace();
+
+        assert_eq!(trace.render(&grid), ".S..\n*^\\*\n*...\n");
+
+        // the right beam lands on the neighbouring splitter and continues down through it
+        let grid = parse_grid(".S..\n.^^.\n....").unwrap();
+        let trace = grid.trace();
+
+        assert_eq!(trace.splits, 1);
+        assert_eq!(trace.render(&grid), ".S..\n*^^.\n*.*.\n");
+
+        // a row of splitters next to each other doesn't nest the beams it splits
+        let grid = parse_grid(&format!(
+            ".S{}\n{}",
+            ".".repeat(999_998),
+            "^".repeat(1_000_000)
+        ))
+        .unwrap();
+        assert_eq!(grid.trace().splits, 1);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let grid = parse_grid("^.S..\n..^..\n.^.^.\n..^..").unwrap();
+
+        let coverage = grid.coverage();
+
+        // the beams of both splitters above merge before reaching the bottom one, but still count separately
+        assert_eq!(coverage.arrivals.len(), 4);
+        assert_eq!(coverage.arrivals.get(&Coord(2, 1)), Some(&1));
+        assert_eq!(coverage.arrivals.get(&Coord(1, 2)), Some(&1));
+        assert_eq!(coverage.arrivals.get(&Coord(3, 2)), Some(&1));
+        assert_eq!(coverage.arrivals.get(&Coord(2, 3)), Some(&2));
+        assert_eq!(coverage.arrivals, grid.trace_with_arrivals().1);
+        assert_eq!(coverage.unreachable, vec![Coord(0, 0)]);
+        assert_eq!(coverage.render(&grid), "x.S..\n..^..\n.^.^.\n..^..\n");
+
+        let input = strip_header(include_str!("../assets/day07/test.txt"));
+        let grid = parse_grid(input).unwrap();
+        let coverage = grid.coverage();
+        let splitters = grid
+            .fields
+            .iter()
+            .filter(|field| **field == Field::Splitter);
+
+        assert_eq!(coverage.arrivals.len(), 21);
+        assert_eq!(
+            coverage.arrivals.len() + coverage.unreachable.len(),
+            splitters.count()
+        );
+        assert_eq!(coverage.arrivals, grid.trace_with_arrivals().1);
+
+        // a beam landing on a splitter next to the one it split at doesn't hit it, as in part 1
+        let grid = parse_grid(".S..\n.^^.\n....").unwrap();
+        let coverage = grid.coverage();
+
+        assert_eq!(Day07::part1(&grid), Answer::from(coverage.arrivals.len()));
+        assert_eq!(coverage.unreachable, vec![Coord(2, 1)]);
+
+        // a mirror sends one side back up into the splitter, which a row sweep would miss
+        let grid = parse_grid("..S.\n..^.\n..\\/\n--.^").unwrap();
+        let coverage = grid.coverage();
+
+        assert_eq!(coverage.arrivals.get(&Coord(2, 1)), Some(&2));
+        assert_eq!(coverage.arrivals.get(&Coord(1, 3)), Some(&1));
+        assert_eq!(coverage.unreachable, vec![Coord(0, 3), Coord(3, 3)]);
+        assert_eq!(coverage.render(&grid), "..S.\n..^.\n..\\/\nx-.x\n");
+    }
+
+    #[test]
+    fn test_large_grid() {
+        let mut input = format!("{}S{}\n", ".".repeat(1001), ".".repeat(998));
+        for _ in 0..1000 {
+            input.push_str(&".^".repeat(1000));
+            input.push('\n');
+        }
+
+        let grid = parse_grid(&input).unwrap();
+
+        assert_eq!(grid.fields.len(), 2_002_000);
+        assert_eq!(
+            grid.sweep(),
+            Sweep {
+                splits: 1,
+                timelines: Some(2)
+            }
+        );
+    }
+}
@@ -1,26 +1,45 @@
 use std::collections::HashSet;
 
-fn main() {
-    let input = include_str!("../../assets/day08/input.txt");
-    let part1 = solve_part1(input, 1000);
-    println!("Part 1: {part1}");
-    
-    let part2 = solve_part2(input);
-    println!("Part 2: {part2}");
+use crate::{Answer, ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<JunctionBox>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_junction_boxes(input)
+    }
+
+    fn part1(boxes: &Self::Input) -> Answer {
+        solve_part1(boxes, 1000).into()
+    }
+
+    fn part2(boxes: &Self::Input) -> Answer {
+        solve_part2(boxes).into()
+    }
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
-struct JunctionBox(isize, isize, isize);
+pub struct JunctionBox(isize, isize, isize);
 
 impl Eq for OrderedFloat {}
 
 impl Ord for OrderedFloat {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).expect("all numbers valid")
+        self.0.partial_cmp(&other.0).expect("all numbers valid")
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+impl PartialOrd for OrderedFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 struct OrderedFloat(f64);
 
 trait EuclidianDistance {
@@ -37,28 +56,30 @@ impl EuclidianDistance for JunctionBox {
     }
 }
 
-fn parse_junction_boxes(input: &str) -> Vec<JunctionBox> {
+fn parse_junction_boxes(input: &str) -> Result<Vec<JunctionBox>, ParseError> {
     input
         .lines()
-        .map(|line| line.split(',').collect::<Vec<_>>())
-        .flat_map(|num_strs| {
-            let first = num_strs
-                .first()
-                .ok_or("invalid input")?
-                .parse()
-                .map_err(|_| "invalid number")?;
-            let second = num_strs
-                .iter()
-                .nth(1)
-                .ok_or("invalid input")?
-                .parse()
-                .map_err(|_| "invalid number")?;
-            let third = num_strs
-                .last()
-                .ok_or("invalid input")?
-                .parse()
-                .map_err(|_| "invalid number")?;
-            Ok::<JunctionBox, String>(JunctionBox(first, second, third))
+        .enumerate()
+        .map(|(idx, line)| {
+            let num_strs: Vec<_> = line.split(',').collect();
+            let [first, second, third] = num_strs[..] else {
+                return Err(
+                    ParseError::new(line, line, "expected three coordinates like 1,2,3")
+                        .at_line(idx),
+                );
+            };
+
+            let parse_num = |num_str: &str| {
+                num_str
+                    .parse()
+                    .map_err(|_| ParseError::new(line, num_str, "invalid number").at_line(idx))
+            };
+
+            Ok(JunctionBox(
+                parse_num(first)?,
+                parse_num(second)?,
+                parse_num(third)?,
+            ))
         })
         .collect()
 }
@@ -79,63 +100,62 @@ fn calculate_distances(boxes: &[JunctionBox]) -> Vec<((JunctionBox, JunctionBox)
     distance_map
 }
 
-fn solve_part1(input: &str, cnt_distances: usize) -> usize {
-    let boxes = parse_junction_boxes(input);
-    let distances = calculate_distances(&boxes);
+fn solve_part1(boxes: &[JunctionBox], cnt_distances: usize) -> usize {
+    let distances = calculate_distances(boxes);
     let mut circuits: Vec<Circuit> = Vec::new();
 
     for ((first_box, second_box), _) in distances.iter().take(cnt_distances) {
         // check if first belongs to a circuit, get it
         let first_circuit_idx = circuits
             .iter()
-            .position(|circuit| circuit.contains_box(&first_box));
+            .position(|circuit| circuit.contains_box(first_box));
         let second_circuit_idx = circuits
             .iter()
-            .position(|circuit| circuit.contains_box(&second_box));
+            .position(|circuit| circuit.contains_box(second_box));
 
         match (first_circuit_idx, second_circuit_idx) {
             // first already part of circuit - add second box to it
             (Some(idx), None) => {
                 let circuit = circuits.get_mut(idx).expect("checked index");
-                circuit.add_box(second_box.clone());
+                circuit.add_box(*second_box);
             }
             // second already part of circuit - add first box to it
             (None, Some(idx)) => {
                 let circuit = circuits.get_mut(idx).expect("checked index");
-                circuit.add_box(first_box.clone());
+                circuit.add_box(*first_box);
             }
             // none part of circuit - create a new one
             (None, None) => {
                 let mut circuit = Circuit::new();
-                circuit.add_box(first_box.clone());
-                circuit.add_box(second_box.clone());
+                circuit.add_box(*first_box);
+                circuit.add_box(*second_box);
                 circuits.push(circuit);
             }
             // both part of circuits - merge them
             (Some(first_idx), Some(second_idx)) => {
                 // already in same circuit - bye
                 if first_idx == second_idx {
-                    continue
+                    continue;
                 }
                 let first_circuit = circuits.get(first_idx).expect("checked index");
                 let second_circuit = circuits.get(second_idx).expect("checked index");
 
                 let merged_circuit = first_circuit.merge(second_circuit);
-                
+
                 let mut indeces = [first_idx, second_idx];
                 indeces.sort();
-                
+
                 for idx in indeces.iter().rev() {
                     circuits.remove(*idx);
                 }
-                
+
                 circuits.push(merged_circuit);
             }
         }
     }
 
     circuits.sort_by_key(|circuit| circuit.len());
-    
+
     circuits
         .iter()
         .rev()
@@ -144,9 +164,8 @@ fn solve_part1(input: &str, cnt_distances: usize) -> usize {
         .product()
 }
 
-fn solve_part2(input: &str) -> usize {
-    let boxes = parse_junction_boxes(input);
-    let distances = calculate_distances(&boxes);
+fn solve_part2(boxes: &[JunctionBox]) -> usize {
+    let distances = calculate_distances(boxes);
     let mut circuits: Vec<Circuit> = Vec::new();
     let mut solution = 0;
 
@@ -163,72 +182,69 @@ fn solve_part2(input: &str) -> usize {
             // first already part of circuit - add second box to it
             (Some(idx), None) => {
                 let circuit = circuits.get_mut(idx).expect("checked index");
-                circuit.add_box(first_box.clone());
-                circuit.add_box(second_box.clone());
+                circuit.add_box(first_box);
+                circuit.add_box(second_box);
                 // everything is connected
                 if circuits.len() == 1 && circuits[0].len() == boxes.len() {
                     solution = (first_box.0 * second_box.0) as usize;
-                    break
+                    break;
                 }
             }
             // second already part of circuit - add first box to it
             (None, Some(idx)) => {
                 let circuit = circuits.get_mut(idx).expect("checked index");
-                circuit.add_box(first_box.clone());
-                circuit.add_box(second_box.clone());
+                circuit.add_box(first_box);
+                circuit.add_box(second_box);
                 // everything is connected
                 if circuits.len() == 1 && circuits[0].len() == boxes.len() {
                     solution = (first_box.0 * second_box.0) as usize;
-                    break
+                    break;
                 }
             }
             // none part of circuit - create a new one
             (None, None) => {
                 let mut circuit = Circuit::new();
-                circuit.add_box(first_box.clone());
-                circuit.add_box(second_box.clone());
+                circuit.add_box(first_box);
+                circuit.add_box(second_box);
                 circuits.push(circuit);
             }
             // both part of circuits - merge them
             (Some(first_idx), Some(second_idx)) => {
                 // already in same circuit - bye
                 if first_idx == second_idx {
-                    continue
+                    continue;
                 }
-                
+
                 let first_circuit = circuits.get(first_idx).expect("checked index");
                 let second_circuit = circuits.get(second_idx).expect("checked index");
 
                 let merged_circuit = first_circuit.merge(second_circuit);
-                
+
                 let mut indeces = [first_idx, second_idx];
                 indeces.sort();
-                
+
                 for idx in indeces.iter().rev() {
                     circuits.remove(*idx);
                 }
-                
+
                 circuits.push(merged_circuit);
                 // everything is connected (all boxes included)
                 if circuits.len() == 1 && circuits[0].len() == boxes.len() {
                     solution = (first_box.0 * second_box.0) as usize;
-                    break
+                    break;
                 }
             }
         }
-        
-    }   
-    return solution
+    }
+    solution
 }
 
 #[derive(Debug)]
 struct Circuit(HashSet<JunctionBox>);
 
-
 impl Circuit {
     fn new() -> Self {
         Self(HashSet::new())
-        
     }
 
     fn contains_box(&self, other: &JunctionBox) -> bool {
@@ -257,21 +273,28 @@ impl Circuit {
 mod tests {
 
     use super::*;
+    use crate::examples::strip_header;
 
     #[test]
     fn test_parsing() {
-        let input = include_str!("../../assets/day08/test.txt");
+        let input = strip_header(include_str!("../assets/day08/test.txt"));
 
-        let boxes = parse_junction_boxes(input);
+        let boxes = parse_junction_boxes(input).unwrap();
         assert_eq!(boxes.len(), 20);
         assert_eq!(boxes[0], JunctionBox(162, 817, 812));
         assert_eq!(boxes[19], JunctionBox(425, 690, 689));
+
+        let err = parse_junction_boxes("162,817,812\n57,618").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "57,618"));
+
+        let err = parse_junction_boxes("162,817,812\n57,6l8,57").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "6l8"));
     }
 
     #[test]
     fn test_calculating_distances() {
-        let input = include_str!("../../assets/day08/test.txt");
-        let boxes = parse_junction_boxes(input);
+        let input = strip_header(include_str!("../assets/day08/test.txt"));
+        let boxes = parse_junction_boxes(input).unwrap();
 
         let distances = calculate_distances(&boxes);
 
@@ -289,16 +312,18 @@ mod tests {
 
     #[test]
     fn test_solve_part1() {
-        let input = include_str!("../../assets/day08/test.txt");
-        let result = solve_part1(input, 10);
+        let input = strip_header(include_str!("../assets/day08/test.txt"));
+        let boxes = parse_junction_boxes(input).unwrap();
+        let result = solve_part1(&boxes, 10);
 
         assert_eq!(40, result);
     }
-    
+
     #[test]
     fn test_solve_part2() {
-        let input = include_str!("../../assets/day08/test.txt");
-        let result = solve_part2(input);
+        let input = strip_header(include_str!("../assets/day08/test.txt"));
+        let boxes = parse_junction_boxes(input).unwrap();
+        let result = solve_part2(&boxes);
 
         assert_eq!(25272, result);
     }
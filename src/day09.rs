@@ -1,16 +1,27 @@
-fn main() {
-    let input = include_str!("../../assets/day09/input.txt");
-    let part1 = solve_part1(input);
+use crate::{Answer, ParseError, Solution};
 
-    println!("Part 1: {}", part1);
+pub struct Day09;
 
-    let part2 = solve_part2(input);
+impl Solution for Day09 {
+    const DAY: u8 = 9;
 
-    println!("Part 2: {}", part2);
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_points(input)
+    }
+
+    fn part1(points: &Self::Input) -> Answer {
+        solve_part1(points).into()
+    }
+
+    fn part2(points: &Self::Input) -> Answer {
+        solve_part2(points).into()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-struct Point(usize, usize);
+pub struct Point(usize, usize);
 
 impl Point {
     fn x(&self) -> usize {
@@ -28,14 +39,18 @@ impl Point {
 
         self.y() > line.start().y() && self.y() <= line.end().y() && line.start().x() > self.x()
     }
-    
+
     fn on_line(&self, line: &Line) -> bool {
         if line.is_horizontal() {
-            return self.x() >= line.start().x() && self.x() <= line.end().x() && line.start().y() == self.y()
+            return self.x() >= line.start().x()
+                && self.x() <= line.end().x()
+                && line.start().y() == self.y();
         }
 
         if line.is_vertical() {
-            return self.y() >= line.start().y() && self.y() <= line.end().y() && line.start().x() == self.x()
+            return self.y() >= line.start().y()
+                && self.y() <= line.end().y()
+                && line.start().x() == self.x();
         }
 
         false
@@ -73,22 +88,33 @@ impl Square {
         if self.corners().len() == 2 {
             return vec![build_line(self.0, self.1)];
         }
-        
+
         let corners = self.corners();
-        
-        vec![build_line(corners[0], corners[1]), build_line(corners[1], corners[2]), build_line(corners[2], corners[3]), build_line(corners[3], corners[0])]
+
+        vec![
+            build_line(corners[0], corners[1]),
+            build_line(corners[1], corners[2]),
+            build_line(corners[2], corners[3]),
+            build_line(corners[3], corners[0]),
+        ]
     }
 }
 
-fn parse_points(input: &str) -> Vec<Point> {
+fn parse_points(input: &str) -> Result<Vec<Point>, ParseError> {
     input
         .lines()
-        .flat_map(|line| line.split_once(','))
-        .flat_map(|(first, second)| {
-            Ok::<Point, String>(Point(
-                first.parse().map_err(|_| "invalid number")?,
-                second.parse().map_err(|_| "invalid number")?,
-            ))
+        .enumerate()
+        .map(|(idx, line)| {
+            let (first, second) = line.split_once(',').ok_or_else(|| {
+                ParseError::new(line, line, "expected point like 7,1").at_line(idx)
+            })?;
+            let parse_num = |num_str: &str| {
+                num_str
+                    .parse()
+                    .map_err(|_| ParseError::new(line, num_str, "invalid number").at_line(idx))
+            };
+
+            Ok(Point(parse_num(first)?, parse_num(second)?))
         })
         .collect()
 }
@@ -158,12 +184,10 @@ impl Shape {
     }
 
     fn point_within(&self, point: &Point) -> bool {
-        
         if self.0.iter().any(|line| point.on_line(line)) {
-            return true
+            return true;
         }
-        
-        
+
         !self
             .get_vertical_lines()
             .iter()
@@ -207,7 +231,7 @@ fn build_shape(points: &[Point]) -> Shape {
         } else {
             points[0]
         };
-        
+
         let line = build_line(this, that);
 
         lines.push(line);
@@ -216,13 +240,10 @@ fn build_shape(points: &[Point]) -> Shape {
     Shape(lines)
 }
 
-fn solve_part1(input: &str) -> usize {
-    let points = parse_points(input);
-    let mut squares = calculate_possible_squares(&points);
+fn solve_part1(points: &[Point]) -> usize {
+    let mut squares = calculate_possible_squares(points);
     squares.sort_by_key(|square| square.area());
 
-    dbg!(squares.len());
-
     squares
         .iter()
         .rev()
@@ -231,10 +252,9 @@ fn solve_part1(input: &str) -> usize {
         .sum()
 }
 
-fn solve_part2(input: &str) -> usize {
-    let points = parse_points(input);
-    let shape = build_shape(&points);
-    let squares = calculate_possible_squares(&points);
+fn solve_part2(points: &[Point]) -> usize {
+    let shape = build_shape(points);
+    let squares = calculate_possible_squares(points);
     let mut squares: Vec<_> = squares
         .iter()
         .filter(|square| {
@@ -246,21 +266,28 @@ fn solve_part2(input: &str) -> usize {
             let fourth_within = shape.point_within(&corners[3]);
             second_within && fourth_within
         })
-        .filter(|square| square.lines().iter().all(|line| {
-            if line.is_horizontal() {
-                return !shape.get_vertical_lines().iter().any(|other| line.intersects(other))
-            }
-            
-            if line.is_vertical() {
-                return !shape.get_horizontal_lines().iter().any(|other| line.intersects(other))
-            }
-            
-            false
-        }))
+        .filter(|square| {
+            square.lines().iter().all(|line| {
+                if line.is_horizontal() {
+                    return !shape
+                        .get_vertical_lines()
+                        .iter()
+                        .any(|other| line.intersects(other));
+                }
+
+                if line.is_vertical() {
+                    return !shape
+                        .get_horizontal_lines()
+                        .iter()
+                        .any(|other| line.intersects(other));
+                }
+
+                false
+            })
+        })
         .collect();
 
     squares.sort_by_key(|square| square.area());
-    dbg!(squares.len());
 
     squares
         .iter()
@@ -273,11 +300,12 @@ fn solve_part2(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::examples::strip_header;
 
     #[test]
     fn test_parsing() {
-        let input = include_str!("../../assets/day09/test.txt");
-        let points = parse_points(input);
+        let input = strip_header(include_str!("../assets/day09/test.txt"));
+        let points = parse_points(input).unwrap();
         assert_eq!(points.len(), 8);
 
         assert_eq!(points[0], Point(7, 1));
@@ -288,6 +316,9 @@ mod tests {
         assert_eq!(points[5], Point(2, 5));
         assert_eq!(points[6], Point(2, 3));
         assert_eq!(points[7], Point(7, 3));
+
+        let err = parse_points("7,1\n11;1").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "11;1"));
     }
 
     #[test]
@@ -304,14 +335,15 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let input = include_str!("../../assets/day09/test.txt");
-        assert_eq!(solve_part1(input), 50);
+        let input = strip_header(include_str!("../assets/day09/test.txt"));
+        let points = parse_points(input).unwrap();
+        assert_eq!(solve_part1(&points), 50);
     }
 
     #[test]
     fn test_build_shape() {
-        let input = include_str!("../../assets/day09/test.txt");
-        let points = parse_points(input);
+        let input = strip_header(include_str!("../assets/day09/test.txt"));
+        let points = parse_points(input).unwrap();
         let shape = build_shape(&points);
 
         assert_eq!(shape.0.len(), 8);
@@ -362,29 +394,30 @@ mod tests {
         assert_eq!(corners[0], Point(11, 1));
         assert_eq!(corners[1], Point(11, 7));
     }
-    
+
     #[test]
     fn test_intersect() {
-        let this = build_line(Point(8,6), Point(10,6));
-        let that = build_line(Point(9,5), Point(9,7));
-        
+        let this = build_line(Point(8, 6), Point(10, 6));
+        let that = build_line(Point(9, 5), Point(9, 7));
+
         assert!(this.intersects(&that));
-        
-        let this = build_line(Point(8,4), Point(10,4));
-        let that = build_line(Point(9,5), Point(9,7));
+
+        let this = build_line(Point(8, 4), Point(10, 4));
+        let that = build_line(Point(9, 5), Point(9, 7));
         assert!(!this.intersects(&that));
-        let this = build_line(Point(3,6), Point(8,6));
-        let that = build_line(Point(9,5), Point(9,7));
+        let this = build_line(Point(3, 6), Point(8, 6));
+        let that = build_line(Point(9, 5), Point(9, 7));
         assert!(!this.intersects(&that));
-        let this = build_line(Point(8,5), Point(10,5));
-        let that = build_line(Point(9,5), Point(9,7));
+        let this = build_line(Point(8, 5), Point(10, 5));
+        let that = build_line(Point(9, 5), Point(9, 7));
         assert!(!this.intersects(&that));
     }
 
     #[test]
     fn test_part2() {
-        let input = include_str!("../../assets/day09/test.txt");
-        let part2 = solve_part2(input);
+        let input = strip_header(include_str!("../assets/day09/test.txt"));
+        let points = parse_points(input).unwrap();
+        let part2 = solve_part2(&points);
 
         assert_eq!(24, part2);
     }
@@ -0,0 +1,1754 @@
+#[cfg(test)]
+use std::collections::HashSet;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    fmt::{self, Display},
+};
+
+use crate::{Answer, Diagnostic, ParseError, Part, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        solve_part1(machines).map_or_else(Answer::Unsolvable, Answer::from)
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        solve_part2(machines).map_or_else(Answer::Unsolvable, Answer::from)
+    }
+
+    fn diagnose(machines: &Self::Input) -> Vec<Diagnostic> {
+        let modes = [(Part::One, Mode::Lights), (Part::Two, Mode::Joltage)];
+
+        machines
+            .iter()
+            .flat_map(|machine| {
+                modes.into_iter().filter_map(|(part, mode)| {
+                    let reason = machine.solve(mode).err()?;
+                    Some(Diagnostic {
+                        line: machine.line,
+                        part,
+                        message: reason.to_string(),
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+struct MachineLight(bool);
+
+impl Display for MachineLight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.0 { '#' } else { '.' })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+struct MachineJoltage(Vec<usize>);
+
+impl Display for MachineJoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", join(&self.0))
+    }
+}
+
+impl MachineJoltage {
+    fn new(val: Vec<usize>) -> Self {
+        Self(val)
+    }
+
+    fn update(&mut self, idx_list: &[usize]) {
+        for idx in idx_list {
+            if let Some(val) = self.0.get_mut(*idx) {
+                *val += 1
+            }
+        }
+    }
+}
+
+impl MachineLight {
+    fn toggle(&mut self) {
+        self.0 = !self.0
+    }
+}
+
+/// Lights and counters a button is wired to, and what a single press of it costs.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Button {
+    wires: Vec<usize>,
+    cost: usize,
+}
+
+impl Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", join(&self.wires))?;
+        if self.cost != 1 {
+            write!(f, "@{}", self.cost)?;
+        }
+        Ok(())
+    }
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// How often each button of a machine is pressed, by button index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Presses(Vec<usize>);
+
+impl Presses {
+    pub fn total(&self) -> usize {
+        self.0.iter().sum()
+    }
+
+    /// Indices of the pressed buttons with how often they are pressed.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(button, count)| (button, *count))
+    }
+}
+
+/// Target a machine is solved for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Lights,
+    Joltage,
+}
+
+/// Why a machine can't reach its target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unsolvable {
+    /// A light has to change, but no button toggles it.
+    UntouchedLight(usize),
+    /// Every button toggles an even number of these lights, but an odd number of them has to change.
+    Parity(Vec<usize>),
+    /// A counter has to increase, but no button increases it.
+    UntouchedCounter(usize),
+    /// The targets of these counters contradict each other.
+    Contradiction(Vec<usize>),
+    /// The targets can only be reached with fractional or negative presses.
+    NoWholeSolution,
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unsolvable::UntouchedLight(light) => {
+                write!(f, "light {light} has to change but no button toggles it")
+            }
+            Unsolvable::Parity(lights) => write!(
+                f,
+                "every button toggles an even number of lights {} but an odd number of them has to change",
+                join(lights)
+            ),
+            Unsolvable::UntouchedCounter(counter) => {
+                write!(
+                    f,
+                    "joltage counter {counter} has a target but no button increases it"
+                )
+            }
+            Unsolvable::Contradiction(counters) => {
+                write!(
+                    f,
+                    "joltage targets of counters {} contradict each other",
+                    join(counters)
+                )
+            }
+            Unsolvable::NoWholeSolution => {
+                write!(
+                    f,
+                    "no whole, non-negative number of presses reaches the joltage targets"
+                )
+            }
+        }
+    }
+}
+
+/// A single button press of a replayed solution and the machine state after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub button: usize,
+    pressed: Button,
+    lights: Vec<MachineLight>,
+    joltage: MachineJoltage,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> [", self.pressed)?;
+        for light in &self.lights {
+            write!(f, "{light}")?;
+        }
+        write!(f, "] {}", self.joltage)
+    }
+}
+
+/// Cost so far, light state and presses of a state queued by [`MachineSolver`].
+#[cfg(test)]
+type Queued = (usize, Vec<MachineLight>, Vec<usize>);
+
+/// Dijkstra over light states, cheapest first - the reference for [`LightSystem`].
+#[cfg(test)]
+struct MachineSolver<'m> {
+    machine: &'m Machine,
+    queue: BinaryHeap<Reverse<Queued>>,
+    visited: HashSet<Vec<MachineLight>>,
+}
+
+#[cfg(test)]
+impl<'m> MachineSolver<'m> {
+    fn new(machine: &'m Machine) -> Self {
+        MachineSolver {
+            machine,
+            queue: BinaryHeap::from([Reverse((
+                0,
+                machine.state.clone(),
+                vec![0; machine.buttons.len()],
+            ))]),
+            visited: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl<'m> Iterator for MachineSolver<'m> {
+    type Item = (Vec<MachineLight>, Presses);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse((cost, current_state, pressed_buttons))) = self.queue.pop() {
+            if !self.visited.insert(current_state.clone()) {
+                continue;
+            }
+            if current_state == self.machine.desired {
+                return Some((current_state, Presses(pressed_buttons)));
+            }
+
+            for (button_idx, button) in self.machine.buttons.iter().enumerate() {
+                let mut state = current_state.clone();
+                for idx in button.wires.iter() {
+                    if let Some(light) = state.get_mut(*idx) {
+                        light.toggle();
+                    }
+                }
+
+                if self.visited.contains(&state) {
+                    continue;
+                }
+
+                let mut buttons = pressed_buttons.clone();
+                buttons[button_idx] += 1;
+                self.queue
+                    .push(Reverse((cost + button.cost, state, buttons)));
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    /// 1 based line of the machine within the input.
+    line: usize,
+    desired: Vec<MachineLight>,
+    joltage: MachineJoltage,
+    target: MachineJoltage,
+    state: Vec<MachineLight>,
+    buttons: Vec<Button>,
+}
+
+impl Machine {
+    fn new(desired: Vec<MachineLight>, buttons: Vec<Button>, target: MachineJoltage) -> Self {
+        let len = desired.len();
+        let jlen = target.0.len();
+        Self {
+            line: 1,
+            desired,
+            joltage: MachineJoltage::new(vec![0; jlen]),
+            target,
+            state: vec![MachineLight(false); len],
+            buttons,
+        }
+    }
+
+    #[cfg(test)]
+    fn solve_iter<'m>(&'m self) -> MachineSolver<'m> {
+        MachineSolver::new(self)
+    }
+
+    /// Cheapest button presses to reach the target of `mode`, or why it can't be reached.
+    ///
+    /// Without explicit costs every press costs 1, so this is the fewest presses.
+    pub fn solve(&self, mode: Mode) -> Result<Presses, Unsolvable> {
+        let presses = match mode {
+            Mode::Lights => LightSystem::new(self)?.min_presses(),
+            Mode::Joltage => JoltageSystem::new(self)?
+                .min_presses()
+                .ok_or(Unsolvable::NoWholeSolution)?,
+        };
+
+        Ok(Presses(presses))
+    }
+
+    /// What pressing the buttons as often as given costs in total.
+    pub fn cost(&self, presses: &Presses) -> usize {
+        presses
+            .iter()
+            .flat_map(|(button, count)| Some(self.buttons.get(button)?.cost * count))
+            .sum()
+    }
+
+    /// Replays the presses button by button and checks they reach the target of `mode`.
+    ///
+    /// Returns every step on the way, e.g. to print a walkthrough.
+    pub fn simulate(&self, presses: &Presses, mode: Mode) -> Result<Vec<Step>, String> {
+        let mut lights = self.state.clone();
+        let mut joltage = self.joltage.clone();
+        let mut steps = Vec::with_capacity(presses.total());
+
+        for (button_idx, count) in presses.iter() {
+            let button = self
+                .buttons
+                .get(button_idx)
+                .ok_or(format!("machine has no button {button_idx}"))?;
+
+            for _ in 0..count {
+                for idx in &button.wires {
+                    if let Some(light) = lights.get_mut(*idx) {
+                        light.toggle();
+                    }
+                }
+                joltage.update(&button.wires);
+
+                steps.push(Step {
+                    button: button_idx,
+                    pressed: button.clone(),
+                    lights: lights.clone(),
+                    joltage: joltage.clone(),
+                });
+            }
+        }
+
+        match mode {
+            Mode::Lights if lights != self.desired => Err(format!(
+                "lights end up as [{}] instead of [{}]",
+                lights
+                    .iter()
+                    .map(MachineLight::to_string)
+                    .collect::<String>(),
+                self.desired
+                    .iter()
+                    .map(MachineLight::to_string)
+                    .collect::<String>()
+            )),
+            Mode::Joltage if joltage != self.target => Err(format!(
+                "joltage ends up as {joltage} instead of {}",
+                self.target
+            )),
+            _ => Ok(steps),
+        }
+    }
+}
+
+/// Fixed size bit vector.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Bits(Vec<u64>);
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn get(&self, idx: usize) -> bool {
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    fn set(&mut self, idx: usize, value: bool) {
+        if value {
+            self.0[idx / 64] |= 1 << (idx % 64);
+        } else {
+            self.0[idx / 64] &= !(1 << (idx % 64));
+        }
+    }
+
+    fn xor(&mut self, other: &Bits) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word ^= other;
+        }
+    }
+
+    /// Whether `self` and `other` share an odd number of set bits.
+    fn odd_overlap(&self, other: &Bits) -> bool {
+        let overlap: u32 = self
+            .0
+            .iter()
+            .zip(&other.0)
+            .map(|(word, other)| (word & other).count_ones())
+            .sum();
+
+        overlap % 2 == 1
+    }
+}
+
+/// Buttons wired to the same lights and counters as a cheaper button, or as an equally cheap one before them.
+///
+/// Swapping such a button for the other one never costs more, so the solvers leave it out.
+fn redundant_buttons(machine: &Machine) -> Vec<bool> {
+    let wires: Vec<Vec<usize>> = machine
+        .buttons
+        .iter()
+        .map(|button| {
+            let mut wires = button.wires.clone();
+            wires.sort_unstable();
+            wires
+        })
+        .collect();
+
+    (0..wires.len())
+        .map(|idx| {
+            (0..wires.len()).any(|other| {
+                wires[other] == wires[idx]
+                    && (machine.buttons[other].cost, other) < (machine.buttons[idx].cost, idx)
+            })
+        })
+        .collect()
+}
+
+/// The lights as linear system over GF(2) - pressing a button twice cancels out, so a light only depends on whether
+/// an odd number of the buttons toggling it is pressed.
+///
+/// After elimination every pivot button is determined by the free buttons, so only the free buttons are enumerated.
+/// With more free buttons than lights, the cheapest way through the light states is searched instead.
+struct LightSystem {
+    /// One reduced row per pivot button, the bit after the buttons holds whether the light has to change.
+    rows: Vec<Bits>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    costs: Vec<usize>,
+    /// Lights each button toggles, `None` for redundant buttons.
+    toggles: Vec<Option<Bits>>,
+    /// Lights that have to change.
+    change: Bits,
+    lights: usize,
+}
+
+impl LightSystem {
+    /// Reduces the system of a machine, failing if the desired lights can't be reached.
+    fn new(machine: &Machine) -> Result<Self, Unsolvable> {
+        let buttons = machine.buttons.len();
+        let lights = machine.desired.len();
+
+        // pressing a button twice cancels out, so of the buttons toggling the same lights only the cheapest is useful
+        let redundant = redundant_buttons(machine);
+
+        // every row also tracks which lights it was combined from, to explain contradictions
+        let mut rows: Vec<Bits> = machine
+            .desired
+            .iter()
+            .zip(&machine.state)
+            .enumerate()
+            .map(|(light, (desired, state))| {
+                let mut row = Bits::new(buttons + 1 + lights);
+                for (idx, button) in machine.buttons.iter().enumerate() {
+                    row.set(idx, !redundant[idx] && button.wires.contains(&light));
+                }
+                row.set(buttons, desired != state);
+                row.set(buttons + 1 + light, true);
+                row
+            })
+            .collect();
+
+        let untouched = (0..lights).find(|light| {
+            rows[*light].get(buttons) && (0..buttons).all(|button| !rows[*light].get(button))
+        });
+        if let Some(light) = untouched {
+            return Err(Unsolvable::UntouchedLight(light));
+        }
+
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+
+        for (col, redundant) in redundant.iter().enumerate() {
+            let rank = pivots.len();
+            let Some(pivot_row) = (rank..rows.len()).find(|row| rows[*row].get(col)) else {
+                if !redundant {
+                    free.push(col);
+                }
+                continue;
+            };
+            rows.swap(rank, pivot_row);
+
+            let pivot = rows[rank].clone();
+            for (idx, row) in rows.iter_mut().enumerate() {
+                if idx != rank && row.get(col) {
+                    row.xor(&pivot);
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        // rows without a pivot are all zero now, unless the lights they combine can't be changed as desired
+        if let Some(row) = rows[pivots.len()..].iter().find(|row| row.get(buttons)) {
+            let lights = (0..lights)
+                .filter(|light| row.get(buttons + 1 + light))
+                .collect();
+            return Err(Unsolvable::Parity(lights));
+        }
+        rows.truncate(pivots.len());
+
+        let toggles = machine
+            .buttons
+            .iter()
+            .zip(&redundant)
+            .map(|(button, redundant)| {
+                (!redundant).then(|| {
+                    let mut toggles = Bits::new(lights);
+                    for light in button.wires.iter().filter(|light| **light < lights) {
+                        toggles.set(*light, true);
+                    }
+                    toggles
+                })
+            })
+            .collect();
+
+        let mut change = Bits::new(lights);
+        for (light, (desired, state)) in machine.desired.iter().zip(&machine.state).enumerate() {
+            change.set(light, desired != state);
+        }
+
+        Ok(Self {
+            rows,
+            pivots,
+            free,
+            costs: machine.buttons.iter().map(|button| button.cost).collect(),
+            toggles,
+            change,
+            lights,
+        })
+    }
+
+    fn min_presses(&self) -> Vec<usize> {
+        if self.free.len() <= self.lights {
+            self.min_presses_by_buttons()
+        } else {
+            self.min_presses_by_lights()
+        }
+    }
+
+    /// Tries every combination of the free buttons.
+    fn min_presses_by_buttons(&self) -> Vec<usize> {
+        let buttons = self.costs.len();
+        let mut presses = Bits::new(buttons + 1);
+        let mut best: Option<(usize, Bits)> = None;
+
+        loop {
+            // each row holds exactly one pivot, so the free buttons decide whether it is pressed
+            for (row, pivot) in self.rows.iter().zip(&self.pivots) {
+                presses.set(*pivot, false);
+                presses.set(*pivot, row.get(buttons) != row.odd_overlap(&presses));
+            }
+
+            let cost = (0..buttons)
+                .filter(|button| presses.get(*button))
+                .map(|button| self.costs[button])
+                .sum();
+            if best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost) {
+                best = Some((cost, presses.clone()));
+            }
+
+            // next combination, counting in binary over the free buttons
+            let Some(carry) = self.free.iter().position(|button| !presses.get(*button)) else {
+                break;
+            };
+            for button in &self.free[..carry] {
+                presses.set(*button, false);
+            }
+            presses.set(self.free[carry], true);
+        }
+
+        let (_, presses) = best.expect("at least the first combination is tried");
+
+        (0..buttons)
+            .map(|button| presses.get(button) as usize)
+            .collect()
+    }
+
+    /// Searches the cheapest way from no changed lights to the lights that have to change, pressing one button at a
+    /// time - the elimination already made sure there is one.
+    fn min_presses_by_lights(&self) -> Vec<usize> {
+        let start = Bits::new(self.lights);
+        let mut costs = HashMap::from([(start.clone(), 0)]);
+        let mut previous: HashMap<Bits, (Bits, usize)> = HashMap::new();
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((cost, lights))) = queue.pop() {
+            if lights == self.change {
+                break;
+            }
+            if costs.get(&lights).is_some_and(|best| *best < cost) {
+                continue;
+            }
+
+            for (button, toggles) in self.toggles.iter().enumerate() {
+                let Some(toggles) = toggles else {
+                    continue;
+                };
+
+                let mut next = lights.clone();
+                next.xor(toggles);
+                let next_cost = cost + self.costs[button];
+
+                if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                    costs.insert(next.clone(), next_cost);
+                    previous.insert(next.clone(), (lights.clone(), button));
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+
+        let mut presses = vec![0; self.costs.len()];
+        let mut lights = self.change.clone();
+        while let Some((before, button)) = previous.get(&lights) {
+            presses[*button] += 1;
+            lights = before.clone();
+        }
+
+        presses
+    }
+}
+
+/// Tolerance of the floating point simplex behind [`JoltageSystem`].
+const EPSILON: f64 = 1e-6;
+
+/// Cheapest whole presses found so far and what they cost.
+type Best = Option<(usize, Vec<usize>)>;
+
+/// The joltage counters as integer program - the cheapest whole presses with `buttons * presses = target`.
+///
+/// Branch and bound over its relaxation to fractional presses, which the simplex method solves. Whenever the cheapest
+/// fractional presses aren't whole, a button is pressed at most as often as rounded down on one branch and at least as
+/// often as rounded up on the other. Branches whose relaxation can't beat the cheapest whole presses so far are skipped.
+struct JoltageSystem {
+    /// Buttons the program decides on, leaving out redundant buttons and those without wires.
+    buttons: Vec<usize>,
+    /// Counters each of `buttons` increases.
+    wires: Vec<Vec<usize>>,
+    costs: Vec<usize>,
+    target: Vec<usize>,
+    /// Buttons of the whole machine, including those left out.
+    machine_buttons: usize,
+}
+
+impl JoltageSystem {
+    /// Sets up the program of a machine, failing if the targets contradict each other.
+    fn new(machine: &Machine) -> Result<Self, Unsolvable> {
+        let counters = machine.target.0.len();
+
+        let untouched = (0..counters).find(|counter| {
+            machine.target.0[*counter] != 0
+                && machine
+                    .buttons
+                    .iter()
+                    .all(|button| !button.wires.contains(counter))
+        });
+        if let Some(counter) = untouched {
+            return Err(Unsolvable::UntouchedCounter(counter));
+        }
+
+        if let Some(counters) = contradiction(machine) {
+            return Err(Unsolvable::Contradiction(counters));
+        }
+
+        let redundant = redundant_buttons(machine);
+        let buttons: Vec<usize> = (0..machine.buttons.len())
+            .filter(|button| !redundant[*button] && !machine.buttons[*button].wires.is_empty())
+            .collect();
+
+        Ok(Self {
+            wires: buttons
+                .iter()
+                .map(|button| machine.buttons[*button].wires.clone())
+                .collect(),
+            costs: buttons
+                .iter()
+                .map(|button| machine.buttons[*button].cost)
+                .collect(),
+            buttons,
+            target: machine.target.0.clone(),
+            machine_buttons: machine.buttons.len(),
+        })
+    }
+
+    fn min_presses(&self) -> Option<Vec<usize>> {
+        let mut best = None;
+        self.branch(self.relax()?, &mut best);
+
+        let (_, presses) = best?;
+        let mut all = vec![0; self.machine_buttons];
+        for (button, count) in self.buttons.iter().zip(presses) {
+            all[*button] = count;
+        }
+
+        Some(all)
+    }
+
+    /// Searches the cheapest whole presses within the bounds of `relaxation`, keeping them in `best` if they beat it.
+    fn branch(&self, relaxation: Relaxation, best: &mut Best) {
+        let presses = relaxation.presses();
+        let cost: f64 = presses
+            .iter()
+            .zip(&self.costs)
+            .map(|(count, cost)| count * *cost as f64)
+            .sum();
+
+        // every press costs a whole number, so whole presses cost at least the rounded up fractional cost
+        let bound = (cost - EPSILON).ceil().max(0.0) as usize;
+        if best
+            .as_ref()
+            .is_some_and(|(best_cost, _)| bound >= *best_cost)
+        {
+            return;
+        }
+
+        let fractional = presses
+            .iter()
+            .map(|count| (count - count.round()).abs())
+            .enumerate()
+            .filter(|(_, off)| *off > EPSILON)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        let Some((col, _)) = fractional else {
+            let presses: Vec<usize> = presses.iter().map(|count| count.round() as usize).collect();
+            let cost = presses
+                .iter()
+                .zip(&self.costs)
+                .map(|(count, cost)| count * cost)
+                .sum();
+
+            // the relaxation is only solved up to the tolerance, so the rounded presses are checked exactly
+            if self.reaches_target(&presses)
+                && best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost)
+            {
+                *best = Some((cost, presses));
+            }
+            return;
+        };
+
+        // the nearer whole number of presses first, which tends to find cheap whole presses early
+        let below = presses[col].floor() as usize;
+        let round_up = presses[col] - below as f64 > 0.5;
+        let (first, second) = if round_up {
+            (
+                relaxation.clone().at_least(col, below + 1),
+                relaxation.at_most(col, below),
+            )
+        } else {
+            (
+                relaxation.clone().at_most(col, below),
+                relaxation.at_least(col, below + 1),
+            )
+        };
+
+        for relaxation in [first, second].into_iter().flatten() {
+            self.branch(relaxation, best);
+        }
+    }
+
+    fn reaches_target(&self, presses: &[usize]) -> bool {
+        let mut joltage = vec![0; self.target.len()];
+        for (wires, count) in self.wires.iter().zip(presses) {
+            wires.iter().for_each(|counter| joltage[*counter] += count);
+        }
+
+        joltage == self.target
+    }
+
+    /// Cheapest fractional presses reaching the target, by the two phase simplex method - `None` if there are none.
+    fn relax(&self) -> Option<Relaxation> {
+        let cols = self.buttons.len();
+        let counters = self.target.len();
+
+        // the first phase starts from an artificial variable per counter, which makes up its whole target
+        let mut rows = vec![vec![0.0; cols + counters]; counters];
+        for (col, wires) in self.wires.iter().enumerate() {
+            wires.iter().for_each(|counter| rows[*counter][col] = 1.0);
+        }
+        for (counter, row) in rows.iter_mut().enumerate() {
+            row[cols + counter] = 1.0;
+        }
+
+        let mut tableau = Tableau {
+            rows,
+            rhs: self.target.iter().map(|val| *val as f64).collect(),
+            basis: (cols..cols + counters).collect(),
+            reduced: Vec::new(),
+        };
+
+        // pushing the artificials down to zero finds any presses reaching the target
+        let mut costs = vec![0.0; cols + counters];
+        costs[cols..].iter_mut().for_each(|cost| *cost = 1.0);
+        tableau.price(&costs);
+        tableau.minimize();
+        if tableau.cost(&costs) > EPSILON {
+            return None;
+        }
+
+        // artificials left in the basis are zero, and only stay there for rows that repeat others
+        for row in 0..tableau.rows.len() {
+            if tableau.basis[row] >= cols
+                && let Some(col) = (0..cols).find(|col| tableau.rows[row][*col].abs() > EPSILON)
+            {
+                tableau.pivot(row, col);
+            }
+        }
+        tableau.drop_columns(cols);
+
+        let costs: Vec<f64> = self.costs.iter().map(|cost| *cost as f64).collect();
+        tableau.price(&costs);
+        tableau.minimize();
+
+        Some(Relaxation {
+            tableau,
+            lower: vec![0; cols],
+        })
+    }
+}
+
+/// Cheapest fractional presses within the bounds of a branch.
+///
+/// Every button is pressed as often as its lower bound up front, and the tableau solves for the presses on top.
+#[derive(Clone)]
+struct Relaxation {
+    tableau: Tableau,
+    lower: Vec<usize>,
+}
+
+impl Relaxation {
+    fn presses(&self) -> Vec<f64> {
+        let mut presses: Vec<f64> = self.lower.iter().map(|lower| *lower as f64).collect();
+        for (col, rhs) in self.tableau.basis.iter().zip(&self.tableau.rhs) {
+            if let Some(presses) = presses.get_mut(*col) {
+                *presses += rhs;
+            }
+        }
+        presses
+    }
+
+    /// Presses the button of `col` at most `max` times - `None` if the target can't be reached then.
+    fn at_most(mut self, col: usize, max: usize) -> Option<Self> {
+        let room = max.checked_sub(self.lower[col])? as f64;
+
+        // a slack variable makes up the difference to the bound, and the row is written in the current basis
+        let slack = self.tableau.reduced.len();
+        self.tableau.rows.iter_mut().for_each(|row| row.push(0.0));
+        self.tableau.reduced.push(0.0);
+
+        let mut row = vec![0.0; slack + 1];
+        row[col] = 1.0;
+        row[slack] = 1.0;
+        let mut rhs = room;
+        if let Some(basic) = self.tableau.basis.iter().position(|basis| *basis == col) {
+            for (val, basic) in row.iter_mut().zip(&self.tableau.rows[basic]) {
+                *val -= basic;
+            }
+            rhs -= self.tableau.rhs[basic];
+        }
+
+        self.tableau.rows.push(row);
+        self.tableau.rhs.push(rhs);
+        self.tableau.basis.push(slack);
+
+        self.tableau.repair().then_some(self)
+    }
+
+    /// Presses the button of `col` at least `min` times - `None` if the target can't be reached then.
+    fn at_least(mut self, col: usize, min: usize) -> Option<Self> {
+        let shift = min.checked_sub(self.lower[col])? as f64;
+        self.lower[col] = min;
+
+        for (row, rhs) in self.tableau.rows.iter().zip(&mut self.tableau.rhs) {
+            *rhs -= row[col] * shift;
+        }
+
+        self.tableau.repair().then_some(self)
+    }
+}
+
+/// Dense simplex tableau of equality constraints over non-negative variables.
+#[derive(Clone)]
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    /// Right hand side of each row, the value of the variable it solves for.
+    rhs: Vec<f64>,
+    /// Column of the variable each row solves for.
+    basis: Vec<usize>,
+    /// How much raising the variable of each column changes the cost.
+    reduced: Vec<f64>,
+}
+
+impl Tableau {
+    fn price(&mut self, costs: &[f64]) {
+        self.reduced = (0..costs.len())
+            .map(|col| {
+                let basic: f64 = self
+                    .rows
+                    .iter()
+                    .zip(&self.basis)
+                    .map(|(row, basis)| costs[*basis] * row[col])
+                    .sum();
+                costs[col] - basic
+            })
+            .collect();
+    }
+
+    fn cost(&self, costs: &[f64]) -> f64 {
+        self.basis
+            .iter()
+            .zip(&self.rhs)
+            .map(|(basis, rhs)| costs[*basis] * rhs)
+            .sum()
+    }
+
+    /// Leaves out the columns from `cols` on, and the rows still solving for one of them.
+    fn drop_columns(&mut self, cols: usize) {
+        let keep: Vec<usize> = (0..self.rows.len())
+            .filter(|row| self.basis[*row] < cols)
+            .collect();
+
+        self.rows = keep
+            .iter()
+            .map(|row| self.rows[*row][..cols].to_vec())
+            .collect();
+        self.rhs = keep.iter().map(|row| self.rhs[*row]).collect();
+        self.basis = keep.iter().map(|row| self.basis[*row]).collect();
+    }
+
+    /// Pivots until no variable lowers the cost any more, by the primal simplex method.
+    ///
+    /// The variables are bounded by the constraints, so the cost can't decrease forever.
+    fn minimize(&mut self) {
+        // Bland's rule - the first column lowering the cost and the tightest row solving for the first variable -
+        // never cycles
+        while let Some(col) = (0..self.reduced.len()).find(|col| self.reduced[*col] < -EPSILON) {
+            let ratios: Vec<(usize, f64)> = (0..self.rows.len())
+                .filter(|row| self.rows[*row][col] > EPSILON)
+                .map(|row| (row, self.rhs[row] / self.rows[row][col]))
+                .collect();
+            let Some(tightest) = ratios
+                .iter()
+                .map(|(_, ratio)| *ratio)
+                .min_by(f64::total_cmp)
+            else {
+                break;
+            };
+            let (row, _) = ratios
+                .iter()
+                .filter(|(_, ratio)| *ratio <= tightest + EPSILON)
+                .min_by_key(|(row, _)| self.basis[*row])
+                .expect("the tightest row is among them");
+
+            self.pivot(*row, col);
+        }
+    }
+
+    /// Pivots until no variable is negative, by the dual simplex method - which keeps the cost as low as possible
+    /// after a bound changed. Returns whether all variables could be made non-negative.
+    fn repair(&mut self) -> bool {
+        while let Some(row) = (0..self.rows.len())
+            .filter(|row| self.rhs[*row] < -EPSILON)
+            .min_by_key(|row| self.basis[*row])
+        {
+            // the column whose reduced cost limits the pivot the most, so no reduced cost turns negative
+            let ratios: Vec<(usize, f64)> = (0..self.reduced.len())
+                .filter(|col| self.rows[row][*col] < -EPSILON)
+                .map(|col| (col, self.reduced[col] / -self.rows[row][col]))
+                .collect();
+            let Some(tightest) = ratios
+                .iter()
+                .map(|(_, ratio)| *ratio)
+                .min_by(f64::total_cmp)
+            else {
+                return false;
+            };
+            let (col, _) = ratios
+                .iter()
+                .find(|(_, ratio)| *ratio <= tightest + EPSILON)
+                .expect("the tightest column is among them");
+
+            self.pivot(row, *col);
+        }
+
+        true
+    }
+
+    /// Makes the variable of `col` the one `row` solves for.
+    fn pivot(&mut self, row: usize, col: usize) {
+        let scale = self.rows[row][col];
+        self.rows[row].iter_mut().for_each(|val| *val /= scale);
+        self.rhs[row] /= scale;
+
+        let pivot = self.rows[row].clone();
+        let pivot_rhs = self.rhs[row];
+        for (idx, (other, rhs)) in self.rows.iter_mut().zip(&mut self.rhs).enumerate() {
+            let factor = other[col];
+            if idx == row || factor == 0.0 {
+                continue;
+            }
+            for (val, pivot) in other.iter_mut().zip(&pivot) {
+                *val -= factor * pivot;
+            }
+            *rhs -= factor * pivot_rhs;
+        }
+
+        let factor = self.reduced[col];
+        for (val, pivot) in self.reduced.iter_mut().zip(&pivot) {
+            *val -= factor * pivot;
+        }
+        self.basis[row] = col;
+    }
+}
+
+/// Counters whose targets contradict each other, found by fraction free elimination over the integers.
+///
+/// Gives up with `None` once the values outgrow an `i128`, which leaves it to the solver to find no presses at all.
+fn contradiction(machine: &Machine) -> Option<Vec<usize>> {
+    let buttons = machine.buttons.len();
+    let counters = machine.target.0.len();
+
+    // every row also tracks which counters it was combined from, to explain contradictions
+    let mut rows: Vec<Vec<i128>> = machine
+        .target
+        .0
+        .iter()
+        .enumerate()
+        .map(|(counter, target)| {
+            let mut row: Vec<i128> = machine
+                .buttons
+                .iter()
+                .map(|button| button.wires.contains(&counter) as i128)
+                .collect();
+            row.push(*target as i128);
+            row.extend((0..counters).map(|other| (other == counter) as i128));
+            row
+        })
+        .collect();
+
+    let mut rank = 0;
+    for col in 0..buttons {
+        let Some(pivot_row) = (rank..rows.len()).find(|row| rows[*row][col] != 0) else {
+            continue;
+        };
+        rows.swap(rank, pivot_row);
+
+        let pivot = rows[rank].clone();
+        for (idx, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if idx == rank || factor == 0 {
+                continue;
+            }
+
+            // fraction free, so everything stays an integer
+            for (val, pivot_val) in row.iter_mut().zip(&pivot) {
+                *val = val
+                    .checked_mul(pivot[col])?
+                    .checked_sub(pivot_val.checked_mul(factor)?)?;
+            }
+            reduce(row);
+        }
+
+        rank += 1;
+    }
+
+    // rows without a pivot are all zero now, unless the targets they combine contradict each other
+    let row = rows[rank..].iter().find(|row| row[buttons] != 0)?;
+
+    Some(
+        (0..counters)
+            .filter(|counter| row[buttons + 1 + counter] != 0)
+            .collect(),
+    )
+}
+
+/// Divides a row by the greatest common divisor of its values.
+fn reduce(row: &mut [i128]) {
+    let gcd = row.iter().fold(0, |gcd, val| {
+        let (mut a, mut b) = (gcd, val.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    });
+
+    if gcd > 1 {
+        row.iter_mut().for_each(|val| *val /= gcd as i128);
+    }
+}
+
+/// A bracketed group of a machine line, holding the text between the brackets.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Lights(&'a str),
+    /// Wires of a button and its optional cost after `@`.
+    Button(&'a str, Option<&'a str>),
+    Joltage(&'a str),
+}
+
+/// Splits a machine line into its `[...]`, `(...)` and `{...}` groups, checking that every bracket is closed.
+fn tokenize(line: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((start, open)) = chars.next() {
+        let close = match open {
+            '[' => ']',
+            '(' => ')',
+            '{' => '}',
+            c if c.is_ascii_whitespace() => continue,
+            c => {
+                return Err(ParseError::new(
+                    line,
+                    &line[start..start + c.len_utf8()],
+                    "expected '[', '(' or '{'",
+                ));
+            }
+        };
+
+        let end = loop {
+            match chars.next() {
+                Some((end, c)) if c == close => break end,
+                Some((idx, c)) if "[](){}".contains(c) => {
+                    return Err(ParseError::new(
+                        line,
+                        &line[idx..idx + 1],
+                        format!("expected '{close}' to close '{open}'"),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    return Err(ParseError::new(
+                        line,
+                        &line[start..start + 1],
+                        format!("unclosed '{open}'"),
+                    ));
+                }
+            }
+        };
+        let inner = &line[start + 1..end];
+
+        tokens.push(match open {
+            '[' => Token::Lights(inner),
+            '{' => Token::Joltage(inner),
+            _ => {
+                // the cost is optional, e.g. (1,3)@5
+                let cost = chars.next_if(|(_, c)| *c == '@').map(|(at, _)| {
+                    let mut cost_end = at + 1;
+                    while let Some((idx, c)) = chars.next_if(|(_, c)| !c.is_ascii_whitespace()) {
+                        cost_end = idx + c.len_utf8();
+                    }
+                    &line[at + 1..cost_end]
+                });
+                Token::Button(inner, cost)
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn parse_state(line: &str, lights: &str) -> Result<Vec<MachineLight>, ParseError> {
+    lights
+        .char_indices()
+        .map(|(offset, c)| match c {
+            '#' => Ok(MachineLight(true)),
+            '.' => Ok(MachineLight(false)),
+            _ => Err(ParseError::new(
+                line,
+                &lights[offset..offset + c.len_utf8()],
+                "expected '#' or '.'",
+            )),
+        })
+        .collect()
+}
+
+fn parse_numbers<'a>(
+    line: &str,
+    input: &'a str,
+) -> impl Iterator<Item = Result<(&'a str, usize), ParseError>> {
+    input.split(',').map(move |num_str| {
+        num_str
+            .parse::<usize>()
+            .map(|num| (num_str, num))
+            .map_err(|_| ParseError::new(line, num_str, "invalid number"))
+    })
+}
+
+fn parse_button(
+    line: &str,
+    wires: &str,
+    cost: Option<&str>,
+    lights: usize,
+) -> Result<Button, ParseError> {
+    let mut parsed: Vec<usize> = Vec::new();
+    for wire in parse_numbers(line, wires) {
+        let (num_str, wire) = wire?;
+        if wire >= lights {
+            return Err(ParseError::new(
+                line,
+                num_str,
+                format!("button wired to light {wire}, but the machine has {lights} lights"),
+            ));
+        }
+        // the solvers count every wire once, so a repeated one would disagree with simulate
+        if parsed.contains(&wire) {
+            return Err(ParseError::new(
+                line,
+                num_str,
+                format!("button wired to light {wire} twice"),
+            ));
+        }
+        parsed.push(wire);
+    }
+
+    let cost = match cost {
+        Some(cost) => cost
+            .parse()
+            .map_err(|_| ParseError::new(line, cost, "invalid cost"))?,
+        None => 1,
+    };
+
+    Ok(Button {
+        wires: parsed,
+        cost,
+    })
+}
+
+fn parse_joltage(line: &str, input: &str, lights: usize) -> Result<MachineJoltage, ParseError> {
+    let vals: Vec<usize> = parse_numbers(line, input)
+        .map(|val| val.map(|(_, val)| val))
+        .collect::<Result<_, _>>()?;
+
+    if vals.len() != lights {
+        return Err(ParseError::new(
+            line,
+            input,
+            format!(
+                "expected {lights} joltage values, one per light, found {}",
+                vals.len()
+            ),
+        ));
+    }
+
+    Ok(MachineJoltage::new(vals))
+}
+
+/// Parses a machine like `[.##.] (3) (1,3)@5 {3,5,4,7}` - lights, then buttons, then joltage.
+fn parse_machine(line: &str) -> Result<Machine, ParseError> {
+    let tokens = tokenize(line)?;
+
+    let Some(Token::Lights(lights)) = tokens.first() else {
+        let start = line.trim_start();
+        return Err(ParseError::new(
+            line,
+            &start[..start.chars().next().map_or(0, char::len_utf8)],
+            "expected lights like [.##.] first",
+        ));
+    };
+    let desired = parse_state(line, lights)?;
+
+    let mut buttons = Vec::new();
+    let mut joltage = None;
+
+    for token in &tokens[1..] {
+        match (token, &joltage) {
+            (Token::Button(wires, cost), None) => {
+                buttons.push(parse_button(line, wires, *cost, desired.len())?)
+            }
+            (Token::Joltage(vals), None) => {
+                joltage = Some(parse_joltage(line, vals, desired.len())?)
+            }
+            (Token::Lights(text) | Token::Button(text, _) | Token::Joltage(text), Some(_)) => {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    "nothing may follow the joltage",
+                ));
+            }
+            (Token::Lights(text), None) => {
+                return Err(ParseError::new(
+                    line,
+                    text,
+                    "expected a single set of lights",
+                ));
+            }
+        }
+    }
+
+    let joltage = joltage.ok_or_else(|| {
+        ParseError::new(
+            line,
+            &line[line.len()..],
+            "expected joltage like {3,5,4,7} last",
+        )
+    })?;
+
+    Ok(Machine::new(desired, buttons, joltage))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let mut machine = parse_machine(line).map_err(|err| err.at_line(idx))?;
+            machine.line = idx + 1;
+            Ok(machine)
+        })
+        .collect()
+}
+
+/// Cheapest total over all machines, failing on the first machine that can't reach its target.
+fn total_cost(machines: &[Machine], mode: Mode) -> Result<usize, String> {
+    machines
+        .iter()
+        .map(|machine| {
+            let presses = machine
+                .solve(mode)
+                .map_err(|reason| format!("machine on line {}: {reason}", machine.line))?;
+            debug_assert!(machine.simulate(&presses, mode).is_ok());
+            Ok(machine.cost(&presses))
+        })
+        .sum()
+}
+
+fn solve_part1(machines: &[Machine]) -> Result<usize, String> {
+    total_cost(machines, Mode::Lights)
+}
+
+fn solve_part2(machines: &[Machine]) -> Result<usize, String> {
+    total_cost(machines, Mode::Joltage)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    use crate::{examples::strip_header, xorshift};
+
+    #[test]
+    fn test_solve_part1() {
+        let input = strip_header(include_str!("../assets/day10/test.txt"));
+        let machines = parse_input(input).unwrap();
+        let part1 = solve_part1(&machines);
+
+        assert_eq!(Ok(7), part1);
+    }
+
+    #[test]
+    fn test_solve_lights_like_bfs() {
+        let example = strip_header(include_str!("../assets/day10/test.txt"));
+        let input = include_str!("../assets/day10/input.txt");
+
+        for machine in parse_input(example)
+            .unwrap()
+            .iter()
+            .chain(&parse_input(input).unwrap())
+        {
+            let presses = machine.solve(Mode::Lights);
+            let bfs = machine.solve_iter().next().map(|(_, presses)| presses);
+
+            assert_eq!(
+                presses.as_ref().ok().map(Presses::total),
+                bfs.as_ref().map(Presses::total),
+                "{machine:?}"
+            );
+            if let Some(bfs) = bfs {
+                assert!(machine.simulate(&bfs, Mode::Lights).is_ok());
+            }
+        }
+
+        // no button toggles the second light
+        let machine = parse_machine("[.#] (0) {1,1}").unwrap();
+        assert_eq!(
+            machine.solve(Mode::Lights),
+            Err(Unsolvable::UntouchedLight(1))
+        );
+        assert_eq!(machine.solve_iter().next(), None);
+    }
+
+    #[test]
+    fn test_weighted_buttons() {
+        // pressing both single buttons is cheaper than the shared one
+        let machine = parse_machine("[##] (0,1)@5 (0) (1)@2 {2,2}").unwrap();
+        assert_eq!(machine.buttons[0].to_string(), "(0,1)@5");
+        assert_eq!(machine.buttons[1].to_string(), "(0)");
+
+        let presses = machine.solve(Mode::Lights).unwrap();
+        assert_eq!(presses, Presses(vec![0, 1, 1]));
+        assert_eq!(machine.cost(&presses), 3);
+
+        let presses = machine.solve(Mode::Joltage).unwrap();
+        assert_eq!(presses, Presses(vec![0, 2, 2]));
+        assert_eq!(machine.cost(&presses), 6);
+
+        let machine = parse_machine("[##] (0,1)@2 (0) (1)@2 {2,2}").unwrap();
+        let presses = machine.solve(Mode::Joltage).unwrap();
+        assert_eq!(presses, Presses(vec![2, 0, 0]));
+        assert_eq!(machine.cost(&presses), 4);
+
+        // weighted lights agree with dijkstra
+        let example = strip_header(include_str!("../assets/day10/test.txt"));
+        for mut machine in parse_input(example).unwrap() {
+            for (idx, button) in machine.buttons.iter_mut().enumerate() {
+                button.cost = idx % 3 * 2 + 1;
+            }
+
+            let presses = machine.solve(Mode::Lights).unwrap();
+            let (_, dijkstra) = machine.solve_iter().next().unwrap();
+            assert_eq!(machine.cost(&presses), machine.cost(&dijkstra));
+        }
+
+        let err = parse_machine("[#] (0)@x {1}").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (9, "x"));
+    }
+
+    #[test]
+    fn test_many_light_buttons() {
+        // identical buttons collapse into the cheapest of them
+        let machine = parse_machine(&format!("[#] {}(0)@2 {{1}}", "(0)@3 ".repeat(66))).unwrap();
+        let presses = machine.solve(Mode::Lights).unwrap();
+        assert_eq!(presses.iter().collect::<Vec<_>>(), vec![(66, 1)]);
+
+        // every combination of 5 lights leaves 26 distinct free buttons, so the light states are searched instead
+        let buttons: Vec<_> = (1..32u32)
+            .map(|mask| {
+                let wires: Vec<_> = (0..5)
+                    .filter(|light| mask & (1 << light) != 0)
+                    .map(|light| light.to_string())
+                    .collect();
+                format!("({})", wires.join(","))
+            })
+            .collect();
+        let machine =
+            parse_machine(&format!("[#....] {} {{1,0,0,0,0}}", buttons.join(" "))).unwrap();
+        let presses = machine.solve(Mode::Lights).unwrap();
+        assert_eq!(machine.cost(&presses), 1);
+        assert!(machine.simulate(&presses, Mode::Lights).is_ok());
+
+        // pressing (0) is dearer than going through two other lights
+        let machine = parse_machine(&format!(
+            "[#....] {} {{1,0,0,0,0}}",
+            buttons.join(" ").replacen("(0)", "(0)@5", 1)
+        ))
+        .unwrap();
+        let presses = machine.solve(Mode::Lights).unwrap();
+        let (_, bfs) = machine.solve_iter().next().unwrap();
+        assert_eq!(machine.cost(&presses), 2);
+        assert_eq!(machine.cost(&presses), machine.cost(&bfs));
+        assert!(machine.simulate(&presses, Mode::Lights).is_ok());
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut bits = Bits::new(70);
+        bits.set(3, true);
+        bits.set(65, true);
+
+        assert!(bits.get(65));
+        assert!(!bits.get(64));
+
+        let mut other = Bits::new(70);
+        other.set(65, true);
+        assert!(bits.odd_overlap(&other));
+
+        other.set(3, true);
+        assert!(!bits.odd_overlap(&other));
+
+        bits.xor(&other);
+        assert_eq!(bits, Bits::new(70));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("[.##.] (3) (1,3) {3,5,4,7}\n[...#.] (0,x) {7,5}").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 12, "x"));
+
+        let err = parse_input("[.#o.] (3) (1,3) {3,5,4,7}").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 4, "o"));
+
+        let cases = [
+            ("[.##. (3) {1,2,3,4}", 7, "(", "expected ']' to close '['"),
+            ("[.##.] (3 {1,2,3,4}", 11, "{", "expected ')' to close '('"),
+            ("[.##.] (3) {1,2,3,4", 12, "{", "unclosed '{'"),
+            ("[.##.] 3 {1,2,3,4}", 8, "3", "expected '[', '(' or '{'"),
+            (
+                "(3) [.##.] {1,2,3,4}",
+                1,
+                "(",
+                "expected lights like [.##.] first",
+            ),
+            (
+                "[.##.] (3) [#] {1,2,3,4}",
+                13,
+                "#",
+                "expected a single set of lights",
+            ),
+            (
+                "[.##.] {1,2,3,4} (3)",
+                19,
+                "3",
+                "nothing may follow the joltage",
+            ),
+            (
+                "[.##.] (3) (1,3)",
+                17,
+                "",
+                "expected joltage like {3,5,4,7} last",
+            ),
+            (
+                "[.##.] (3) (1,4) {1,2,3,4}",
+                15,
+                "4",
+                "button wired to light 4, but the machine has 4 lights",
+            ),
+            (
+                "[.##.] (3) {1,2,3}",
+                13,
+                "1,2,3",
+                "expected 4 joltage values, one per light, found 3",
+            ),
+            ("[.##.] (3)@ {1,2,3,4}", 12, "", "invalid cost"),
+            (
+                "[.#] (1,1) (0) {0,2}",
+                9,
+                "1",
+                "button wired to light 1 twice",
+            ),
+        ];
+
+        for (line, column, text, message) in cases {
+            let err = parse_machine(line).unwrap_err();
+            assert_eq!(
+                (err.column, err.text.as_str(), err.message.as_str()),
+                (column, text, message),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        let input = strip_header(include_str!("../assets/day10/test.txt"));
+        let machines = parse_input(input).unwrap();
+        let part2 = Day10::part2(&machines);
+
+        assert_eq!(Answer::from(33usize), part2);
+    }
+
+    #[test]
+    fn test_solve_joltage() {
+        let input = strip_header(include_str!("../assets/day10/test.txt"));
+        let machines = parse_input(input).unwrap();
+
+        let totals: Vec<usize> = machines
+            .iter()
+            .map(|machine| {
+                let presses = machine.solve(Mode::Joltage).unwrap();
+                assert!(machine.simulate(&presses, Mode::Joltage).is_ok());
+                presses.total()
+            })
+            .collect();
+
+        assert_eq!(totals, vec![10, 12, 11]);
+
+        // eight buttons are left free, far too many to try every count up to the targets
+        let machine = parse_machine(
+            "[.....] (0) (1) (2) (3) (4) (0,1) (1,2) (2,3) (3,4) (0,4) (0,2) (1,3) (2,4) {200,200,200,200,200}",
+        )
+        .unwrap();
+        let presses = machine.solve(Mode::Joltage).unwrap();
+        assert!(machine.simulate(&presses, Mode::Joltage).is_ok());
+        assert_eq!(presses.total(), 500);
+
+        // both counters share the only button, so they can't reach different targets
+        let machine = parse_machine("[.#] (0,1) {3,4}").unwrap();
+        assert_eq!(
+            machine.solve(Mode::Joltage),
+            Err(Unsolvable::Contradiction(vec![0, 1]))
+        );
+    }
+
+    #[test]
+    fn test_solve_many_joltage_counters() {
+        let mut next = xorshift(0x5851_f42d_4c95_7f2d);
+
+        // targets in the thousands with a few buttons left free after elimination, then dozens of free buttons
+        for (counters, buttons, max_presses) in [(30, 32, 300), (24, 28, 10), (10, 40, 50)] {
+            let mut line = format!("[{}]", ".".repeat(counters));
+            let mut target = vec![0; counters];
+            let mut cost = 0;
+            for _ in 0..buttons {
+                let wires: Vec<usize> = (0..counters).filter(|_| next(3) == 0).collect();
+                let presses = next(max_presses) as usize;
+                wires.iter().for_each(|counter| target[*counter] += presses);
+                cost += presses;
+                line += &format!(" ({})", join(&wires));
+            }
+            line += &format!(" {{{}}}", join(&target));
+
+            let machine = parse_machine(&line).unwrap();
+            let start = Instant::now();
+            let presses = machine.solve(Mode::Joltage).unwrap();
+            assert!(start.elapsed() < Duration::from_secs(5));
+
+            assert!(machine.simulate(&presses, Mode::Joltage).is_ok());
+            assert!(machine.cost(&presses) <= cost);
+        }
+    }
+
+    #[test]
+    fn test_contradiction_of_many_counters() {
+        let mut next = xorshift(0x9e37_79b9_7f4a_7c15);
+        let (counters, buttons) = (50, 50);
+
+        let wires: Vec<Vec<usize>> = (0..buttons)
+            .map(|_| (0..counters).filter(|_| next(2) == 0).collect())
+            .collect();
+        let mut target = vec![0; counters];
+        for wires in &wires {
+            let presses = next(100) as usize;
+            wires.iter().for_each(|counter| target[*counter] += presses);
+        }
+
+        let line = |wires: &[Vec<usize>], target: &[usize]| {
+            let buttons: Vec<_> = wires
+                .iter()
+                .map(|wires| {
+                    let wires: Vec<_> = wires.iter().map(|w| w.to_string()).collect();
+                    format!("({})", wires.join(","))
+                })
+                .collect();
+            let target: Vec<_> = target.iter().map(|t| t.to_string()).collect();
+            format!(
+                "[{}] {} {{{}}}",
+                ".".repeat(target.len()),
+                buttons.join(" "),
+                target.join(",")
+            )
+        };
+
+        // the elimination outgrows an i64 on this many counters, but the targets agree
+        let machine = parse_machine(&line(&wires, &target)).unwrap();
+        assert_eq!(contradiction(&machine), None);
+
+        // a copy of the first counter that wants one more
+        let mut wires = wires;
+        wires
+            .iter_mut()
+            .filter(|wires| wires.contains(&0))
+            .for_each(|wires| wires.push(counters));
+        target.push(target[0] + 1);
+
+        let machine = parse_machine(&line(&wires, &target)).unwrap();
+        assert_eq!(contradiction(&machine), Some(vec![0, counters]));
+    }
+
+    #[test]
+    fn test_diagnose() {
+        let input = "[##.] (0,1) (1,2) {1,1,0}\n[#..] (0,1) (1,2) {1,1,3}\n[...] (0) (1) {1,1,1}\n[...] (0,1) (1,2) (0,2) {1,1,1}";
+        let machines = parse_input(input).unwrap();
+
+        let diagnostics: Vec<_> = Day10::diagnose(&machines)
+            .iter()
+            .map(Diagnostic::to_string)
+            .collect();
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                "line 2, part 1: every button toggles an even number of lights 0,1,2 but an odd number of them has to change",
+                "line 2, part 2: joltage targets of counters 0,1,2 contradict each other",
+                "line 3, part 2: joltage counter 2 has a target but no button increases it",
+                "line 4, part 2: no whole, non-negative number of presses reaches the joltage targets",
+            ]
+        );
+
+        // every combination of 5 counters leaves 26 buttons free, which is no reason to give up on a machine
+        let buttons: Vec<String> = (1..32u32)
+            .map(|wires| {
+                let wires: Vec<usize> = (0..5).filter(|wire| wires & (1 << wire) != 0).collect();
+                format!("({})", join(&wires))
+            })
+            .collect();
+        let machines =
+            parse_input(&format!("[#.#..] {} {{3,5,4,7,2}}", buttons.join(" "))).unwrap();
+        assert_eq!(Day10::diagnose(&machines), Vec::new());
+    }
+
+    #[test]
+    fn test_unsolvable_parts() {
+        let machines = parse_input("[.#] (0) {1,1}\n[##] (0,1) {2,2}").unwrap();
+
+        assert_eq!(
+            Day10::part1(&machines),
+            Answer::Unsolvable(
+                "machine on line 1: light 1 has to change but no button toggles it".to_string()
+            )
+        );
+        assert_eq!(
+            Day10::part2(&machines).to_string(),
+            "unsolvable: machine on line 1: joltage counter 1 has a target but no button increases it"
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let machine = parse_machine("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+
+        let presses = machine.solve(Mode::Lights).unwrap();
+        assert_eq!(presses.iter().collect::<Vec<_>>(), vec![(1, 1), (3, 1)]);
+
+        let steps = machine.simulate(&presses, Mode::Lights).unwrap();
+        let walkthrough: Vec<_> = steps.iter().map(Step::to_string).collect();
+        assert_eq!(
+            walkthrough,
+            vec!["(1,3) -> [.#.#] {0,1,0,1}", "(2,3) -> [.##.] {0,1,1,2}"]
+        );
+
+        let err = machine.simulate(&presses, Mode::Joltage).unwrap_err();
+        assert_eq!(err, "joltage ends up as {0,1,1,2} instead of {3,5,4,7}");
+
+        let err = machine
+            .simulate(&Presses(vec![1]), Mode::Lights)
+            .unwrap_err();
+        assert_eq!(err, "lights end up as [...#] instead of [.##.]");
+
+        assert!(
+            machine
+                .simulate(&Presses(vec![0, 0, 0, 0, 0, 0, 1]), Mode::Lights)
+                .is_err()
+        );
+    }
+}
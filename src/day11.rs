@@ -1,14 +1,25 @@
 use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let input = include_str!("../../assets/day11/input.txt");
-    let part1 = solve_part1(input);
+use crate::{Answer, ParseError, Solution};
 
-    println!("Part 1: {part1}");
-    
-    let part2 = solve_part2(input);
+pub struct Day11;
 
-    println!("Part 2: {part2}");
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = HashMap<String, Device>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(devices: &Self::Input) -> Answer {
+        solve_part1(devices).into()
+    }
+
+    fn part2(devices: &Self::Input) -> Answer {
+        solve_part2(devices).into()
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -19,7 +30,7 @@ enum OutConn {
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
-struct Device {
+pub struct Device {
     name: String,
     out: Vec<OutConn>,
 }
@@ -32,21 +43,27 @@ impl Device {
         }
     }
 
+    #[cfg(test)]
     fn is_start(&self) -> bool {
-        return self.name == "you";
+        self.name == "you"
     }
 
+    #[cfg(test)]
     fn goes_out(&self) -> bool {
         self.out.iter().any(|conn| conn == &OutConn::End)
     }
 }
 
-fn parse_device(line: &str) -> Option<Device> {
-    let name = line.split(':').next()?;
-    let out = line
-        .split(':')
-        .skip(1)
-        .next()?
+fn parse_device(line: &str) -> Result<Device, ParseError> {
+    let (name, out) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(line, line, "expected device like aaa: bbb ccc"))?;
+
+    if name.is_empty() {
+        return Err(ParseError::new(line, name, "missing device name"));
+    }
+
+    let out = out
         .split_ascii_whitespace()
         .map(|name| match name {
             "you" => OutConn::Start,
@@ -55,32 +72,31 @@ fn parse_device(line: &str) -> Option<Device> {
         })
         .collect();
 
-    Some(Device::new(name, out))
+    Ok(Device::new(name, out))
 }
 
-fn parse_input(input: &str) -> HashMap<String, Device> {
+fn parse_input(input: &str) -> Result<HashMap<String, Device>, ParseError> {
     input
         .lines()
-        .flat_map(parse_device)
-        .map(|device| (device.name.clone(), device))
+        .enumerate()
+        .map(|(idx, line)| {
+            let device = parse_device(line).map_err(|err| err.at_line(idx))?;
+            Ok((device.name.clone(), device))
+        })
         .collect()
 }
 
-fn solve_part1(input: &str) -> usize {
-    let devices = parse_input(input);
-
+fn solve_part1(devices: &HashMap<String, Device>) -> usize {
     if let Some(start) = devices.get("you") {
-        return find_paths(start, &devices);
+        return find_paths(start, devices);
     }
 
     0
 }
 
-fn solve_part2(input: &str) -> usize {
-    let devices = parse_input(input);
+fn solve_part2(devices: &HashMap<String, Device>) -> usize {
     if let Some(start) = devices.get("svr") {
-        dbg!(&start);
-        return find_paths_crossing_dac_fft(start, &devices);
+        return find_paths_crossing_dac_fft(start, devices);
     }
 
     0
@@ -89,44 +105,43 @@ fn solve_part2(input: &str) -> usize {
 fn find_paths_crossing_dac_fft(current: &Device, devices: &HashMap<String, Device>) -> usize {
     let mut visited = HashSet::new();
     let mut cache: HashMap<(String, bool, bool), usize> = HashMap::new();
-    
 
     fn recursive_path_search(
         current: &Device,
         devices: &HashMap<String, Device>,
         visited: &mut HashSet<String>,
         seen: (bool, bool),
-        cache: &mut HashMap<(String, bool, bool), usize>
+        cache: &mut HashMap<(String, bool, bool), usize>,
     ) -> usize {
         let mut count = 0;
         visited.insert(current.name.clone());
-        
+
         if let Some(count) = cache.get(&(current.name.clone(), seen.0, seen.1)) {
-            return *count
+            return *count;
         }
 
         let mut seen_dac = seen.0;
         let mut seen_fft = seen.1;
         for outgoing in current.out.iter() {
             let new_count = match outgoing {
-                OutConn::Device(name) => {  
+                OutConn::Device(name) => {
+                    // cycle - skip
                     if visited.contains(name) {
-                        dbg!("yes this is a cycle");
                         continue;
                     }
                     let Some(device) = devices.get(name) else {
                         continue;
                     };
-                    
+
                     if name == "dac" {
                         seen_dac = true;
                     }
-                    
+
                     if name == "fft" {
                         seen_fft = true;
                     }
 
-                    recursive_path_search(&device, devices, visited, (seen_dac, seen_fft), cache)
+                    recursive_path_search(device, devices, visited, (seen_dac, seen_fft), cache)
                 }
                 OutConn::Start => {
                     if visited.contains("you") {
@@ -136,9 +151,8 @@ fn find_paths_crossing_dac_fft(current: &Device, devices: &HashMap<String, Devic
                         continue;
                     };
 
-                    recursive_path_search(&device, devices, visited, (seen_dac, seen_fft), cache)
-                    
-                },
+                    recursive_path_search(device, devices, visited, (seen_dac, seen_fft), cache)
+                }
                 OutConn::End => {
                     if seen_dac && seen_fft {
                         1
@@ -152,7 +166,7 @@ fn find_paths_crossing_dac_fft(current: &Device, devices: &HashMap<String, Devic
         }
 
         visited.remove(&current.name);
-        
+
         cache.insert((current.name.clone(), seen.0, seen.1), count);
 
         count
@@ -183,7 +197,7 @@ fn find_paths(current: &Device, devices: &HashMap<String, Device>) -> usize {
                         continue;
                     }
 
-                    recursive_path_search(&device, devices, visited)
+                    recursive_path_search(device, devices, visited)
                 }
                 OutConn::Start => 0,
                 OutConn::End => 1,
@@ -203,12 +217,13 @@ fn find_paths(current: &Device, devices: &HashMap<String, Device>) -> usize {
 mod tests {
 
     use super::*;
+    use crate::examples::strip_header;
 
     #[test]
     fn test_parsing() {
-        let input = include_str!("../../assets/day11/test.txt");
+        let input = strip_header(include_str!("../assets/day11/test.txt"));
 
-        let devices = parse_input(input);
+        let devices = parse_input(input).unwrap();
 
         assert_eq!(devices.len(), 10);
 
@@ -286,22 +301,30 @@ mod tests {
         assert!(devices.get("fff").unwrap().goes_out());
         assert!(devices.get("ggg").unwrap().goes_out());
         assert!(devices.get("iii").unwrap().goes_out());
+
+        let err = parse_input("aaa: you hhh\nyou bbb ccc").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.text.as_str()),
+            (2, 1, "you bbb ccc")
+        );
     }
 
     #[test]
     fn test_solve_part1() {
-        let input = include_str!("../../assets/day11/test.txt");
+        let input = strip_header(include_str!("../assets/day11/test.txt"));
 
-        let result = solve_part1(input);
+        let devices = parse_input(input).unwrap();
+        let result = solve_part1(&devices);
 
         assert_eq!(5, result);
     }
-    
+
     #[test]
     fn test_solve_part2() {
-        let input = include_str!("../../assets/day11/test2.txt");
+        let input = strip_header(include_str!("../assets/day11/test2.txt"));
 
-        let result = solve_part2(input);
+        let devices = parse_input(input).unwrap();
+        let result = solve_part2(&devices);
 
         assert_eq!(2, result);
     }
@@ -0,0 +1,100 @@
+use std::fmt::{self, Display};
+
+/// Error raised by a day's parser, pointing at the offending text of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column (in characters) of the offending text.
+    pub column: usize,
+    pub text: String,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `text` within `line`.
+    ///
+    /// The column is derived from where `text` is located in `line`, so `text` should be a subslice of it.
+    /// Line number and day default to 1 and 0 - set them via [`ParseError::at_line`] and [`ParseError::for_day`].
+    pub fn new(line: &str, text: &str, message: impl Into<String>) -> Self {
+        let line_start = line.as_ptr() as usize;
+        let text_start = text.as_ptr() as usize;
+
+        let offset =
+            if text_start >= line_start && text_start + text.len() <= line_start + line.len() {
+                text_start - line_start
+            } else {
+                line.find(text).unwrap_or(line.len())
+            };
+
+        Self {
+            day: 0,
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_string(),
+            source_line: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line from a 0-based index, e.g. as returned by `lines().enumerate()`.
+    pub fn at_line(mut self, line_idx: usize) -> Self {
+        self.line = line_idx + 1;
+        self
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        writeln!(
+            f,
+            "day {:02}: {} at line {}, column {}",
+            self.day, self.message, self.line, self.column
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {padding}{carets}")
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_column_from_subslice() {
+        let line = "12-x8";
+        let err = ParseError::new(line, &line[3..], "invalid number").at_line(2);
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.text, "x8");
+    }
+
+    #[test]
+    fn test_caret_diagnostic() {
+        let line = "162,8x7,812";
+        let err = ParseError::new(line, &line[4..7], "invalid number")
+            .at_line(9)
+            .for_day(8);
+
+        assert_eq!(
+            err.to_string(),
+            "day 08: invalid number at line 10, column 5\n   |\n10 | 162,8x7,812\n   |     ^^^"
+        );
+    }
+}
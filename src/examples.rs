@@ -0,0 +1,158 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{ParseError, answers::KnownAnswers};
+
+/// Prefix of the header lines declaring the expected answers of an example, e.g. `# part1: 21`.
+const HEADER_PREFIX: &str = "# ";
+
+/// Example input like `assets/dayNN/test.txt` together with the answers declared in its header.
+///
+/// ```text
+/// # part1: 21
+/// # part2: 40
+/// .......S.......
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example<'a> {
+    pub answers: KnownAnswers,
+    pub input: &'a str,
+}
+
+impl<'a> Example<'a> {
+    pub fn parse(text: &'a str) -> Result<Self, ParseError> {
+        let input = strip_header(text);
+        let header = &text[..text.len() - input.len()];
+
+        let header: Vec<_> = header
+            .lines()
+            .map(|line| &line[HEADER_PREFIX.len()..])
+            .collect();
+        let answers = KnownAnswers::parse(&header.join("\n"))?;
+
+        Ok(Self { answers, input })
+    }
+}
+
+/// Input of an example without its header, for tests that assert on it themselves.
+pub fn strip_header(text: &str) -> &str {
+    let mut rest = text;
+
+    while rest.starts_with(HEADER_PREFIX) {
+        rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+
+    rest
+}
+
+/// Finds all example files `dayNN/test*.txt` below `dir`, ordered by day and name.
+pub fn discover(dir: &Path) -> Result<Vec<(u8, PathBuf)>, String> {
+    let read_dir = |dir: &Path| {
+        fs::read_dir(dir).map_err(|err| format!("could not read '{}': {err}", dir.display()))
+    };
+
+    let mut examples = Vec::new();
+
+    for entry in read_dir(dir)?.flatten() {
+        let name = entry.file_name();
+        let Some(day) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse().ok())
+        else {
+            continue;
+        };
+
+        for file in read_dir(&entry.path())?.flatten() {
+            let name = file.file_name();
+            let name = name.to_string_lossy();
+
+            if name.starts_with("test") && name.ends_with(".txt") {
+                examples.push((day, file.path()));
+            }
+        }
+    }
+
+    examples.sort();
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        Part, get_day,
+        runner::{self, Verification},
+    };
+
+    #[test]
+    fn test_parse_example() {
+        let example = Example::parse("# part1: 21\n# part2: 40\n.S.\n.^.\n").unwrap();
+
+        assert_eq!(example.answers.get(Part::One), Some("21"));
+        assert_eq!(example.answers.get(Part::Two), Some("40"));
+        assert_eq!(example.input, ".S.\n.^.\n");
+
+        let example = Example::parse(".S.\n# part1: 21").unwrap();
+
+        assert_eq!(example.answers, KnownAnswers::default());
+        assert_eq!(example.input, ".S.\n# part1: 21");
+
+        let err = Example::parse("# part3: 1\n.S.").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (1, "part3"));
+    }
+
+    #[test]
+    fn test_strip_header() {
+        assert_eq!(strip_header("# part2: 2\nsvr: aaa"), "svr: aaa");
+        assert_eq!(strip_header("# part1: 1"), "");
+        assert_eq!(strip_header("L68\nL30"), "L68\nL30");
+    }
+
+    /// Solves every example under `assets` and checks the answers declared in its header.
+    #[test]
+    fn test_examples() {
+        let mut checked = 0;
+        let mut failures = Vec::new();
+
+        for (day, path) in discover(Path::new("assets")).unwrap() {
+            let Some(puzzle) = get_day(day) else {
+                continue;
+            };
+
+            let text = fs::read_to_string(&path).unwrap();
+            let example = Example::parse(&text)
+                .unwrap_or_else(|err| panic!("'{}' is malformed\n{err}", path.display()));
+
+            let parts: Vec<_> = Part::ALL
+                .into_iter()
+                .filter(|part| example.answers.get(*part).is_some())
+                .collect();
+
+            let results = match runner::run(puzzle, example.input, &parts) {
+                Ok(results) => results,
+                Err(err) => {
+                    failures.push(format!("{}: {err}", path.display()));
+                    continue;
+                }
+            };
+
+            for result in results {
+                let expected = example.answers.get(result.part);
+                let verification = Verification::new(result, expected);
+                checked += 1;
+
+                if verification.is_failure() {
+                    failures.push(format!("{}: {verification}", path.display()));
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+        assert!(checked > 0, "no example answers found");
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to a directory laid out like `assets` (`dayNN/input.txt`).
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "assets";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(InputSource),
+    Empty(InputSource),
+    Io(InputSource, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(source) => write!(
+                f,
+                "input file '{source}' not found - pass --input <path|->, or set {INPUT_DIR_VAR}"
+            ),
+            InputError::Empty(source) => write!(f, "input '{source}' is empty"),
+            InputError::Io(source, err) => write!(f, "could not read input '{source}': {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Directory configured via [`INPUT_DIR_VAR`], if any.
+pub fn input_dir() -> Option<PathBuf> {
+    env::var_os(INPUT_DIR_VAR).map(PathBuf::from)
+}
+
+/// Path of a per-day file like `dayNN/input.txt` within `input_dir`, falling back to the repo `assets` folder.
+pub fn day_file(day: u8, name: &str, input_dir: Option<&Path>) -> PathBuf {
+    let dir = input_dir.unwrap_or(Path::new(DEFAULT_INPUT_DIR));
+
+    dir.join(format!("day{day:02}")).join(name)
+}
+
+/// Resolves where the input of a day comes from.
+///
+/// An explicit argument wins (`-` means stdin), then `input_dir`, then the repo `assets` folder.
+pub fn resolve(day: u8, arg: Option<&str>, input_dir: Option<&Path>) -> InputSource {
+    match arg {
+        Some("-") => InputSource::Stdin,
+        Some(path) => InputSource::File(PathBuf::from(path)),
+        None => InputSource::File(day_file(day, "input.txt", input_dir)),
+    }
+}
+
+pub fn read(source: &InputSource) -> Result<String, InputError> {
+    let input = match source {
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| InputError::Io(source.clone(), err))?;
+            input
+        }
+        InputSource::File(path) => fs::read_to_string(path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => InputError::NotFound(source.clone()),
+            _ => InputError::Io(source.clone(), err),
+        })?,
+    };
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty(source.clone()));
+    }
+
+    Ok(input)
+}
+
+/// Loads the input of a day, honouring [`INPUT_DIR_VAR`] when no explicit argument is given.
+pub fn load(day: u8, arg: Option<&str>) -> Result<String, InputError> {
+    let input_dir = input_dir();
+    let source = resolve(day, arg, input_dir.as_deref());
+
+    read(&source)
+}
+
+/// FNV-1a hash of an input as hex, to tell which input produced an answer without revealing it.
+pub fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve(8, Some("-"), None), InputSource::Stdin);
+        assert_eq!(
+            resolve(8, Some("my/input.txt"), Some(Path::new("/inputs"))),
+            InputSource::File(PathBuf::from("my/input.txt"))
+        );
+        assert_eq!(
+            resolve(8, None, Some(Path::new("/inputs"))),
+            InputSource::File(PathBuf::from("/inputs/day08/input.txt"))
+        );
+        assert_eq!(
+            resolve(11, None, None),
+            InputSource::File(PathBuf::from("assets/day11/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_missing_and_empty() {
+        let missing = InputSource::File(PathBuf::from("assets/day99/input.txt"));
+        assert!(matches!(read(&missing), Err(InputError::NotFound(_))));
+
+        let path = env::temp_dir().join("aoc2025-empty-input.txt");
+        fs::write(&path, "\n  \n").unwrap();
+        let empty = InputSource::File(path.clone());
+        assert!(matches!(read(&empty), Err(InputError::Empty(_))));
+        fs::remove_file(path).unwrap();
+
+        let existing = InputSource::File(PathBuf::from("assets/day08/test.txt"));
+        assert!(
+            read(&existing)
+                .unwrap()
+                .starts_with("# part2: 25272\n162,817,812")
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash("L68\nL30"), hash("L68\nL31"));
+    }
+}
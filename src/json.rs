@@ -0,0 +1,142 @@
+use std::fmt::{self, Display, Write};
+
+/// Minimal JSON value, written compactly via [`Display`].
+///
+/// Objects keep their insertion order so the output is stable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i128),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Self {
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{value}"),
+            Json::Number(value) => write!(f, "{value}"),
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                f.write_char('[')?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(fields) => {
+                f.write_char('{')?;
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    f.write_char('"')
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+macro_rules! impl_json_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Json {
+                fn from(value: $ty) -> Self {
+                    Json::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_from_number!(u8, u32, u64, usize, i64);
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_write_json() {
+        let json = Json::object([
+            ("day", Json::from(8u8)),
+            ("answer", Json::from("96672")),
+            ("solved", Json::from(true)),
+            ("hash", Json::from(None::<String>)),
+            ("parts", Json::Array(vec![Json::from(1u8), Json::from(2u8)])),
+        ]);
+
+        assert_eq!(
+            json.to_string(),
+            r#"{"day":8,"answer":"96672","solved":true,"hash":null,"parts":[1,2]}"#
+        );
+    }
+
+    #[test]
+    fn test_escape_strings() {
+        let json = Json::from("line \"1\"\n\t\\ \u{1}");
+
+        assert_eq!(json.to_string(), r#""line \"1\"\n\t\\ \u0001""#);
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use bench::{BenchOptions, DayBench};
+pub use error::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+mod error;
+pub mod examples;
+pub mod input;
+pub mod json;
+pub mod runner;
+pub mod scaffold;
+
+/// Common interface every day implements - parse the input once, then solve both parts on it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Problems in a well formed input that keep a part from being solved, e.g. a machine without a solution.
+    fn diagnose(_input: &Self::Input) -> Vec<Diagnostic> {
+        Vec::new()
+    }
+
+    /// Lines walking through how a part is solved step by step, for days that support it.
+    fn trace(_input: &Self::Input, _part: Part) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Explains why a part can't be solved for some line of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1 based line number within the input.
+    pub line: usize,
+    pub part: Part,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, part {}: {}",
+            self.line, self.part, self.message
+        )
+    }
+}
+
+/// Displayable answer of a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Solved(String),
+    Unimplemented,
+    /// Set by the runner when a part does not finish within its deadline.
+    Timeout,
+    /// The input has no answer, with the reason why.
+    Unsolvable(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Solved(value) => write!(f, "{value}"),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+            Answer::Timeout => write!(f, "timeout"),
+            Answer::Unsolvable(reason) => write!(f, "unsolvable: {reason}"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Solved(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(usize, u64, u128, isize, i64, String, &str);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{value}' - expected 1 or 2")),
+        }
+    }
+}
+
+/// Object safe wrapper around [`Solution`] so days can be driven without knowing their input type.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    /// Parses the input once and solves the requested parts in order, timing each part.
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Answer, Duration)>, ParseError>;
+
+    /// Times parsing and both parts separately, skipping parts without a solution.
+    fn bench(&self, input: &str, options: &BenchOptions) -> Result<DayBench, ParseError>;
+
+    /// Parses the input and lists what keeps its parts from being solved, see [`Solution::diagnose`].
+    fn diagnose(&self, input: &str) -> Result<Vec<Diagnostic>, ParseError>;
+
+    /// Parses the input and walks through solving a part, see [`Solution::trace`].
+    fn trace(&self, input: &str, part: Part) -> Result<Vec<String>, ParseError>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Answer, Duration)>, ParseError> {
+        let input = S::parse(input).map_err(|err| err.for_day(S::DAY))?;
+
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&input),
+                    Part::Two => S::part2(&input),
+                };
+                (answer, start.elapsed())
+            })
+            .collect();
+
+        Ok(answers)
+    }
+
+    fn bench(&self, input: &str, options: &BenchOptions) -> Result<DayBench, ParseError> {
+        let parsed = S::parse(input).map_err(|err| err.for_day(S::DAY))?;
+
+        let parse = bench::measure(options, || S::parse(input));
+        let part1 = (S::part1(&parsed) != Answer::Unimplemented)
+            .then(|| bench::measure(options, || S::part1(&parsed)));
+        let part2 = (S::part2(&parsed) != Answer::Unimplemented)
+            .then(|| bench::measure(options, || S::part2(&parsed)));
+
+        Ok(DayBench {
+            day: S::DAY,
+            parse,
+            part1,
+            part2,
+        })
+    }
+
+    fn diagnose(&self, input: &str) -> Result<Vec<Diagnostic>, ParseError> {
+        let input = S::parse(input).map_err(|err| err.for_day(S::DAY))?;
+
+        Ok(S::diagnose(&input))
+    }
+
+    fn trace(&self, input: &str, part: Part) -> Result<Vec<String>, ParseError> {
+        let input = S::parse(input).map_err(|err| err.for_day(S::DAY))?;
+
+        Ok(S::trace(&input, part))
+    }
+}
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+];
+
+pub fn get_day(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|puzzle| puzzle.day() == day).copied()
+}
+
+/// Reproducible xorshift stream for tests, every call yields a number below `bound`.
+#[cfg(test)]
+pub(crate) fn xorshift(seed: u64) -> impl FnMut(u64) -> u64 {
+    let mut state = seed;
+    move |bound| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    }
+}
@@ -0,0 +1,410 @@
+use std::{
+    fmt::{self, Display},
+    panic,
+    sync::{
+        Arc,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{Answer, ParseError, Part, Puzzle, bench, json::Json};
+
+/// Answer of a single part of a day, ready to be printed by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn is_solved(&self) -> bool {
+        matches!(self.answer, Answer::Solved(_))
+    }
+
+    /// Record for `--format json` - answers are strings as they may not fit into a JSON number.
+    pub fn to_json(&self, input_hash: &str) -> Json {
+        let (status, answer, error) = match &self.answer {
+            Answer::Solved(answer) => ("ok", Some(answer.as_str()), None),
+            Answer::Unimplemented => ("unimplemented", None, None),
+            Answer::Timeout => ("timeout", None, None),
+            Answer::Unsolvable(reason) => ("unsolvable", None, Some(reason.as_str())),
+        };
+
+        let mut fields = vec![
+            ("day", Json::from(self.day)),
+            ("part", part_json(self.part)),
+            ("status", Json::from(status)),
+            ("answer", Json::from(answer)),
+        ];
+        if let Some(error) = error {
+            fields.push(("error", Json::from(error)));
+        }
+        fields.extend([
+            ("elapsed_ns", Json::Number(self.elapsed.as_nanos() as i128)),
+            ("input_hash", Json::from(input_hash)),
+        ]);
+
+        Json::object(fields)
+    }
+}
+
+fn part_json(part: Part) -> Json {
+    match part {
+        Part::One => Json::from(1u8),
+        Part::Two => Json::from(2u8),
+    }
+}
+
+/// Record for `--format json` of a part that could not be solved, e.g. because its input is missing or malformed.
+pub fn error_json(day: u8, part: Part, message: &str, input_hash: Option<&str>) -> Json {
+    Json::object([
+        ("day", Json::from(day)),
+        ("part", part_json(part)),
+        ("status", Json::from("error")),
+        ("error", Json::from(message)),
+        ("input_hash", Json::from(input_hash)),
+    ])
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.answer {
+            Answer::Timeout => write!(
+                f,
+                "Day {:02} Part {}: TIMEOUT (after {})",
+                self.day,
+                self.part,
+                bench::format_duration(self.elapsed)
+            ),
+            _ => write!(f, "Day {:02} Part {}: {}", self.day, self.part, self.answer),
+        }
+    }
+}
+
+/// Outcome of checking a [`PartResult`] against its known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub result: PartResult,
+    pub verdict: Verdict,
+}
+
+impl Verification {
+    pub fn new(result: PartResult, expected: Option<&str>) -> Self {
+        let verdict = match (expected, &result.answer) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Answer::Solved(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        };
+
+        Self { result, verdict }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self.verdict, Verdict::Fail { .. })
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let PartResult {
+            day, part, answer, ..
+        } = &self.result;
+
+        match &self.verdict {
+            Verdict::Pass => write!(f, "Day {day:02} Part {part}: PASS ({answer})"),
+            Verdict::Fail { expected } => write!(
+                f,
+                "Day {day:02} Part {part}: FAIL (expected {expected}, got {answer})"
+            ),
+            Verdict::Missing => write!(f, "Day {day:02} Part {part}: MISSING (got {answer})"),
+        }
+    }
+}
+
+pub fn run(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+) -> Result<Vec<PartResult>, ParseError> {
+    let results = puzzle
+        .solve(input, parts)?
+        .into_iter()
+        .zip(parts)
+        .map(|((answer, elapsed), part)| PartResult {
+            day: puzzle.day(),
+            part: *part,
+            answer,
+            elapsed,
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Like [`run`], but solves every part on a worker thread and gives up on it after `timeout`.
+///
+/// A part that times out is reported as [`Answer::Timeout`]. Its worker cannot be stopped and keeps running
+/// detached until the process exits, while the remaining parts are solved on fresh workers.
+pub fn run_with_timeout(
+    puzzle: &'static dyn Puzzle,
+    input: Arc<str>,
+    parts: &[Part],
+    timeout: Option<Duration>,
+) -> Result<Vec<PartResult>, ParseError> {
+    let mut results = Vec::new();
+
+    for &part in parts {
+        let input = Arc::clone(&input);
+        let solved = with_timeout(timeout, move || puzzle.solve(&input, &[part]));
+
+        let (answer, elapsed) = match solved {
+            Some(solved) => solved?.remove(0),
+            None => (Answer::Timeout, timeout.unwrap_or_default()),
+        };
+
+        results.push(PartResult {
+            day: puzzle.day(),
+            part,
+            answer,
+            elapsed,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Walks through solving a part like [`Puzzle::trace`] on a worker thread, `None` if it takes longer than `timeout`.
+pub fn trace_with_timeout(
+    puzzle: &'static dyn Puzzle,
+    input: Arc<str>,
+    part: Part,
+    timeout: Option<Duration>,
+) -> Result<Option<Vec<String>>, ParseError> {
+    with_timeout(timeout, move || puzzle.trace(&input, part)).transpose()
+}
+
+/// Runs `job` on a worker thread and waits at most `timeout` for it, `None` if it takes longer.
+///
+/// A worker that panics brings the panic over to the calling thread.
+fn with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    job: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let (sender, receiver) = mpsc::channel();
+
+    let worker = thread::spawn(move || {
+        // the receiver is gone if the job already timed out
+        let _ = sender.send(job());
+    });
+
+    let done = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match done {
+        Ok(done) => Some(done),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => match worker.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("worker always sends before it finishes"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::day05::Day05;
+
+    #[test]
+    fn test_run_selected_part() {
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+
+        let results = run(&Day05, input, &[Part::Two]).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].to_string(), "Day 05 Part 2: 14");
+    }
+
+    #[test]
+    fn test_unimplemented_part() {
+        let results = run(&Sleepy, "0", &Part::ALL).unwrap();
+
+        assert!(results[0].is_solved());
+        assert!(!results[1].is_solved());
+        assert_eq!(results[1].to_string(), "Day 99 Part 2: unimplemented");
+    }
+
+    #[test]
+    fn test_verification() {
+        let result = |answer: Answer| PartResult {
+            day: 8,
+            part: Part::One,
+            answer,
+            elapsed: Duration::ZERO,
+        };
+
+        let check = Verification::new(result(40usize.into()), Some("40"));
+        assert_eq!(check.verdict, Verdict::Pass);
+        assert_eq!(check.to_string(), "Day 08 Part 1: PASS (40)");
+
+        let check = Verification::new(result(41usize.into()), Some("40"));
+        assert!(check.is_failure());
+        assert_eq!(
+            check.to_string(),
+            "Day 08 Part 1: FAIL (expected 40, got 41)"
+        );
+
+        let check = Verification::new(result(Answer::Unimplemented), Some("40"));
+        assert!(check.is_failure());
+
+        let check = Verification::new(result(Answer::Unimplemented), None);
+        assert_eq!(check.verdict, Verdict::Missing);
+        assert_eq!(
+            check.to_string(),
+            "Day 08 Part 1: MISSING (got unimplemented)"
+        );
+    }
+
+    /// Sleeps for the given milliseconds in part 2 and its trace, the part is unimplemented for `0`.
+    struct Sleepy;
+
+    impl crate::Solution for Sleepy {
+        const DAY: u8 = 99;
+
+        type Input = u64;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::new(input, input, "expected milliseconds"))
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            (*input).into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            if *input == 0 {
+                return Answer::Unimplemented;
+            }
+
+            thread::sleep(Duration::from_millis(*input));
+            (*input).into()
+        }
+
+        fn trace(input: &Self::Input, part: Part) -> Vec<String> {
+            if part == Part::Two {
+                thread::sleep(Duration::from_millis(*input));
+            }
+
+            vec![format!("slept {input}ms")]
+        }
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        let timeout = Some(Duration::from_millis(50));
+
+        let results = run_with_timeout(&Sleepy, "5000".into(), &Part::ALL, timeout).unwrap();
+
+        assert_eq!(results[0].answer, Answer::from(5000u64));
+        assert_eq!(results[1].answer, Answer::Timeout);
+        assert_eq!(
+            results[1].to_string(),
+            "Day 99 Part 2: TIMEOUT (after 50.00ms)"
+        );
+
+        let results = run_with_timeout(&Sleepy, "1".into(), &Part::ALL, timeout).unwrap();
+        assert!(results.iter().all(PartResult::is_solved));
+
+        let results = run_with_timeout(&Sleepy, "1".into(), &[Part::Two], None).unwrap();
+        assert!(results[0].is_solved());
+
+        assert!(run_with_timeout(&Sleepy, "x".into(), &Part::ALL, timeout).is_err());
+    }
+
+    #[test]
+    fn test_trace_with_timeout() {
+        let timeout = Some(Duration::from_millis(50));
+
+        let trace = trace_with_timeout(&Sleepy, "5000".into(), Part::One, timeout).unwrap();
+        assert_eq!(trace, Some(vec!["slept 5000ms".to_string()]));
+
+        let trace = trace_with_timeout(&Sleepy, "5000".into(), Part::Two, timeout).unwrap();
+        assert_eq!(trace, None);
+
+        assert!(trace_with_timeout(&Sleepy, "x".into(), Part::One, timeout).is_err());
+    }
+
+    #[test]
+    fn test_json_records() {
+        let result = PartResult {
+            day: 8,
+            part: Part::Two,
+            answer: 25272usize.into(),
+            elapsed: Duration::from_micros(12),
+        };
+
+        assert_eq!(
+            result.to_json("af63dc4c8601ec8c").to_string(),
+            r#"{"day":8,"part":2,"status":"ok","answer":"25272","elapsed_ns":12000,"input_hash":"af63dc4c8601ec8c"}"#
+        );
+
+        let result = PartResult {
+            answer: Answer::Unimplemented,
+            ..result
+        };
+        assert!(
+            result
+                .to_json("")
+                .to_string()
+                .contains(r#""status":"unimplemented","answer":null"#)
+        );
+
+        let result = PartResult {
+            answer: Answer::Unsolvable("no button".to_string()),
+            ..result
+        };
+        assert!(!result.is_solved());
+        assert!(
+            result
+                .to_json("")
+                .to_string()
+                .contains(r#""status":"unsolvable","answer":null,"error":"no button""#)
+        );
+
+        assert_eq!(
+            error_json(8, Part::One, "input is empty", None).to_string(),
+            r#"{"day":8,"part":1,"status":"error","error":"input is empty","input_hash":null}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "3-5\n10-1x4\n\n1";
+
+        let err = run(&Day05, input, &Part::ALL).unwrap_err();
+
+        assert_eq!(err.day, 5);
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 4);
+        assert_eq!(err.text, "1x4");
+    }
+}
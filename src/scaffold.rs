@@ -0,0 +1,232 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const ASSET_FILES: [&str; 3] = ["input.txt", "test.txt", "answers.txt"];
+
+/// Source of a new day implementing [`crate::Solution`], with a test wired to its example file.
+pub fn template(day: u8) -> String {
+    format!(
+        r#"use crate::{{Answer, ParseError, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Answer {{
+        Answer::Unimplemented
+    }}
+
+    fn part2(_input: &Self::Input) -> Answer {{
+        Answer::Unimplemented
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+
+    use super::*;
+    use crate::examples::strip_header;
+
+    #[test]
+    fn test_part1() {{
+        let input = strip_header(include_str!("../assets/day{day:02}/test.txt"));
+        let input = Day{day:02}::parse(input).unwrap();
+
+        // replace with the answer of the example
+        assert_eq!(Day{day:02}::part1(&input), Answer::Unimplemented);
+    }}
+}}
+"#
+    )
+}
+
+/// Adds the `mod` declaration and the `DAYS` entry of a day to the source of `lib.rs`, keeping both sorted.
+pub fn register(lib_src: &str, day: u8) -> Result<String, String> {
+    let mod_line = format!("pub mod day{day:02};");
+    let entry_line = format!("    &day{day:02}::Day{day:02},");
+
+    if lib_src.lines().any(|line| line == mod_line) {
+        return Err(format!("day {day:02} is already registered"));
+    }
+
+    let mut lines: Vec<&str> = lib_src.lines().collect();
+
+    let mod_idx = insert_idx(&lines, day, |line| {
+        line.strip_prefix("pub mod day")?.strip_suffix(';')
+    })
+    .ok_or("could not find the day modules in lib.rs")?;
+    lines.insert(mod_idx, &mod_line);
+
+    let entry_idx = insert_idx(&lines, day, |line| {
+        line.strip_prefix("    &day")?
+            .split_once("::")
+            .map(|(num, _)| num)
+    })
+    .ok_or("could not find the DAYS list in lib.rs")?;
+    lines.insert(entry_idx, &entry_line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Line index where `day` belongs among the lines whose day number is extracted by `day_of`.
+fn insert_idx(lines: &[&str], day: u8, day_of: impl Fn(&str) -> Option<&str>) -> Option<usize> {
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, line)| Some((idx, day_of(line)?.parse().ok()?)))
+        .collect();
+
+    let (first_idx, _) = days.first()?;
+
+    let idx = days
+        .iter()
+        .filter(|(_, other)| *other < day)
+        .map(|(idx, _)| idx + 1)
+        .next_back()
+        .unwrap_or(*first_idx);
+
+    Some(idx)
+}
+
+/// Creates the solution file, registers it in `lib.rs` and adds placeholder assets below `root`.
+///
+/// Refuses to touch anything if the solution already exists, and never overwrites existing asset files.
+pub fn create(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {day} - expected 1 to 25"));
+    }
+
+    let lib_path = root.join("src").join("lib.rs");
+    let solution_path = root.join("src").join(format!("day{day:02}.rs"));
+    let assets_path = root.join("assets").join(format!("day{day:02}"));
+
+    let lib_src = fs::read_to_string(&lib_path)
+        .map_err(|err| format!("could not read '{}': {err}", lib_path.display()))?;
+
+    if solution_path.exists() {
+        return Err(format!("'{}' already exists", solution_path.display()));
+    }
+
+    let lib_src = register(&lib_src, day)?;
+
+    let write = |path: &Path, content: &str| {
+        fs::write(path, content)
+            .map_err(|err| format!("could not write '{}': {err}", path.display()))
+    };
+
+    let mut created = Vec::new();
+
+    write(&solution_path, &template(day))?;
+    created.push(solution_path);
+
+    write(&lib_path, &lib_src)?;
+    created.push(lib_path);
+
+    fs::create_dir_all(&assets_path)
+        .map_err(|err| format!("could not create '{}': {err}", assets_path.display()))?;
+
+    for name in ASSET_FILES {
+        let path = assets_path.join(name);
+        if path.exists() {
+            continue;
+        }
+
+        write(&path, "")?;
+        created.push(path);
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::env;
+
+    use super::*;
+
+    const LIB_SRC: &str = "pub mod answers;
+pub mod day01;
+pub mod day03;
+mod error;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_register() {
+        let lib_src = register(LIB_SRC, 2).unwrap();
+
+        assert_eq!(
+            lib_src,
+            "pub mod answers;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+mod error;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+
+        let lib_src = register(LIB_SRC, 12).unwrap();
+        assert!(lib_src.contains("pub mod day03;\npub mod day12;\nmod error;"));
+        assert!(lib_src.contains("    &day03::Day03,\n    &day12::Day12,\n];"));
+
+        assert!(register(LIB_SRC, 3).is_err());
+    }
+
+    #[test]
+    fn test_template() {
+        let src = template(12);
+
+        assert!(src.contains("pub struct Day12;"));
+        assert!(src.contains("const DAY: u8 = 12;"));
+        assert!(src.contains(r#"include_str!("../assets/day12/test.txt")"#));
+    }
+
+    #[test]
+    fn test_create_refuses_to_overwrite() {
+        let root = env::temp_dir().join("aoc2025-scaffold");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("assets").join("day02")).unwrap();
+        fs::write(root.join("src").join("lib.rs"), LIB_SRC).unwrap();
+        fs::write(root.join("assets").join("day02").join("input.txt"), "11-22").unwrap();
+
+        let created = create(&root, 2).unwrap();
+
+        assert_eq!(created.len(), 4);
+        assert_eq!(
+            fs::read_to_string(root.join("assets").join("day02").join("input.txt")).unwrap(),
+            "11-22"
+        );
+        assert!(root.join("assets").join("day02").join("test.txt").exists());
+        assert!(
+            fs::read_to_string(root.join("src").join("lib.rs"))
+                .unwrap()
+                .contains("&day02::Day02,")
+        );
+
+        assert!(create(&root, 2).is_err());
+        assert!(create(&root, 26).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}